@@ -5,7 +5,7 @@ use arctic_runtime::{
 	CollatorSelectionConfig, CouncilConfig, CouncilMembershipConfig, DemocracyConfig, EVMConfig,
 	GenesisConfig, IndicesConfig, ParachainInfoConfig, PolkadotXcmConfig, SS58Prefix,
 	SessionConfig, SessionKeys, Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig,
-	TechnicalMembershipConfig, VestingConfig,
+	TechnicalMembershipConfig, VestingConfig, AIRDROP_VESTING_TERMS,
 };
 use cumulus_primitives_core::ParaId;
 use hex_literal::hex;
@@ -225,6 +225,7 @@ fn make_genesis(
 		airdrop: AirdropConfig {
 			creditor_account: airdrop_creditor_account,
 			merkle_root: AIRDROP_MERKLE_ROOT,
+			vesting_terms: AIRDROP_VESTING_TERMS,
 		},
 		technical_membership: TechnicalMembershipConfig {
 			members: technical_committee.try_into().unwrap(),
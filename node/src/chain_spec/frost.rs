@@ -5,7 +5,7 @@ use frost_runtime::{
 	BalancesConfig, CouncilConfig, CouncilMembershipConfig, DemocracyConfig, EVMConfig,
 	EthereumConfig, GenesisConfig, GrandpaConfig, IndicesConfig, SS58Prefix, SessionConfig,
 	Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig, TechnicalMembershipConfig,
-	TreasuryPalletId, WASM_BINARY,
+	TreasuryPalletId, AIRDROP_VESTING_TERMS, WASM_BINARY,
 };
 use hex_literal::hex;
 use sc_chain_spec::Properties;
@@ -242,6 +242,7 @@ fn testnet_genesis(
 		airdrop: AirdropConfig {
 			creditor_account: airdrop_creditor_account,
 			merkle_root: AIRDROP_MERKLE_ROOT,
+			vesting_terms: AIRDROP_VESTING_TERMS,
 		},
 		technical_membership: TechnicalMembershipConfig {
 			members: technical_committee_membership.try_into().unwrap(),
@@ -8,7 +8,7 @@ use snow_runtime::{
 	CollatorSelectionConfig, CouncilConfig, CouncilMembershipConfig, DemocracyConfig, EVMConfig,
 	GenesisConfig, IndicesConfig, ParachainInfoConfig, PolkadotXcmConfig, SS58Prefix,
 	SessionConfig, SessionKeys, Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig,
-	TechnicalMembershipConfig, TreasuryPalletId, VestingConfig,
+	TechnicalMembershipConfig, TreasuryPalletId, VestingConfig, AIRDROP_VESTING_TERMS,
 };
 use sp_core::{crypto::UncheckedInto, sr25519, Pair, Public};
 use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Verify};
@@ -257,6 +257,7 @@ fn make_genesis(
 		airdrop: AirdropConfig {
 			creditor_account: airdrop_creditor_account,
 			merkle_root: AIRDROP_MERKLE_ROOT,
+			vesting_terms: AIRDROP_VESTING_TERMS,
 		},
 		technical_membership: TechnicalMembershipConfig {
 			members: technical_committee.try_into().unwrap(),
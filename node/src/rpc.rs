@@ -8,6 +8,10 @@ use fc_rpc::{
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use fp_storage::EthereumStorageSchema;
 use jsonrpsee::RpcModule;
+use pallet_airdrop_rpc::{
+	Airdrop, AirdropApiServer, AirdropClaimQueue, AirdropClaimQueueApiServer,
+};
+use pallet_fees_split_rpc::{FeesSplit, FeesSplitApiServer};
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 use sc_client_api::{AuxStore, Backend, BlockchainEvents, StateBackend, StorageProvider};
 use sc_network::NetworkService;
@@ -88,7 +92,7 @@ where
 }
 
 /// Full client dependencies
-pub struct FullDeps<C, P, A: ChainApi> {
+pub struct FullDeps<C, P, A: ChainApi, S> {
 	/// The client instance to use.
 	pub client: Arc<C>,
 	/// Transaction pool instance.
@@ -113,11 +117,13 @@ pub struct FullDeps<C, P, A: ChainApi> {
 	pub overrides: Arc<OverrideHandle<Block>>,
 	/// Cache for Ethereum block data.
 	pub block_data_cache: Arc<EthBlockDataCacheTask<Block>>,
+	/// Offchain storage where airdrop claims are queued.
+	pub offchain_storage: Option<S>,
 }
 
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P, BE, A>(
-	deps: FullDeps<C, P, A>,
+	deps: FullDeps<C, P, A, BE::OffchainStorage>,
 	subscription_task_executor: SubscriptionTaskExecutor,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
@@ -132,6 +138,8 @@ where
 		+ 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ pallet_airdrop_rpc::AirdropRuntimeApi<Block, AccountId, Balance, BlockNumber>
+		+ pallet_fees_split_rpc::FeesSplitRuntimeApi<Block, Balance, BlockNumber>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ BlockBuilder<Block>,
@@ -155,10 +163,16 @@ where
 		fee_history_cache,
 		overrides,
 		block_data_cache,
+		offchain_storage,
 	} = deps;
 
 	io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(Airdrop::new(client.clone()).into_rpc())?;
+	io.merge(FeesSplit::new(client.clone()).into_rpc())?;
+	if let Some(offchain_storage) = offchain_storage {
+		io.merge(AirdropClaimQueue::new(offchain_storage, deny_unsafe).into_rpc())?;
+	}
 
 	io.merge(
 		Eth::new(
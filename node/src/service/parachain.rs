@@ -278,6 +278,8 @@ where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ pallet_airdrop_rpc::AirdropRuntimeApi<Block, AccountId, Balance, BlockNumber>
+		+ pallet_fees_split_rpc::FeesSplitRuntimeApi<Block, Balance, BlockNumber>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>,
@@ -364,6 +366,15 @@ where
 			warp_sync: None,
 		})?;
 
+	if parachain_config.offchain_worker.enabled {
+		sc_service::build_offchain_workers(
+			&parachain_config,
+			task_manager.spawn_handle(),
+			client.clone(),
+			network.clone(),
+		);
+	}
+
 	let filter_pool: FilterPool = Arc::new(std::sync::Mutex::new(BTreeMap::new()));
 	let fee_history_cache: FeeHistoryCache = Arc::new(std::sync::Mutex::new(BTreeMap::new()));
 	let overrides = crate::rpc::overrides_handle(client.clone());
@@ -423,6 +434,7 @@ where
 		let client = client.clone();
 		let network = network.clone();
 		let transaction_pool = transaction_pool.clone();
+		let offchain_storage = sc_client_api::Backend::offchain_storage(&*backend);
 
 		Box::new(move |deny_unsafe, subscription| {
 			let deps = crate::rpc::FullDeps {
@@ -438,6 +450,7 @@ where
 				fee_history_cache: fee_history_cache.clone(),
 				block_data_cache: block_data_cache.clone(),
 				overrides: overrides.clone(),
+				offchain_storage: offchain_storage.clone(),
 			};
 
 			crate::rpc::create_full(deps, subscription).map_err(Into::into)
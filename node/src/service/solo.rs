@@ -285,6 +285,7 @@ pub fn start_frost_node(config: Configuration) -> Result<TaskManager, ServiceErr
 		let client = client.clone();
 		let network = network.clone();
 		let transaction_pool = transaction_pool.clone();
+		let offchain_storage = sc_client_api::Backend::offchain_storage(&*backend);
 
 		Box::new(move |deny_unsafe, subscription| {
 			let deps = crate::rpc::FullDeps {
@@ -300,6 +301,7 @@ pub fn start_frost_node(config: Configuration) -> Result<TaskManager, ServiceErr
 				fee_history_cache: fee_history_cache.clone(),
 				block_data_cache: block_data_cache.clone(),
 				overrides: overrides.clone(),
+				offchain_storage: offchain_storage.clone(),
 			};
 
 			let io = crate::rpc::create_full(deps, subscription)
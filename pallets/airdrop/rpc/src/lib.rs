@@ -0,0 +1,374 @@
+//! RPC interface for the airdrop pallet.
+
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use codec::{Codec, Decode, Encode};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{offchain::OffchainStorage, Bytes, H160, H256};
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, MultiSignature};
+
+pub use pallet_airdrop_runtime_api::{
+	AirdropApi as AirdropRuntimeApi, ClaimPreview, MerkleProofCheck, PartialSnapshotsPage,
+	PendingExchangeClaim, QueuedClaim, SnapshotKey, CLAIM_QUEUE_KEY, MAX_QUEUED_CLAIMS,
+};
+
+#[rpc(client, server)]
+pub trait AirdropApi<BlockHash, ResponseType, Balance, PartialSnapshotsType> {
+	/// Preview what given icon address will receive by claiming given amount
+	#[method(name = "airdrop_previewClaim")]
+	fn preview_claim(
+		&self,
+		campaign_id: u32,
+		icon_address: H160,
+		amount: NumberOrHex,
+		defi_user: bool,
+		at: Option<BlockHash>,
+	) -> RpcResult<ResponseType>;
+
+	/// Compute leaf hash and root of given merkle proof and compare
+	/// it with the root stored on chain
+	#[method(name = "airdrop_verifyMerkleProof")]
+	fn verify_merkle_proof(
+		&self,
+		campaign_id: u32,
+		icon_address: H160,
+		amount: NumberOrHex,
+		defi_user: bool,
+		proofs: Vec<H256>,
+		at: Option<BlockHash>,
+	) -> RpcResult<MerkleProofCheck>;
+
+	/// List exchange accounts that are yet to make their claim
+	#[method(name = "airdrop_pendingExchangeClaims")]
+	fn pending_exchange_claims(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PendingExchangeClaim<Balance>>>;
+
+	/// List partially claimed snapshots among at most `limit` snapshots
+	/// after `start_key`. Pass `nextKey` of a page to get the next one
+	#[method(name = "airdrop_partialSnapshots")]
+	fn partial_snapshots(
+		&self,
+		start_key: Option<SnapshotKey>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<PartialSnapshotsType>;
+}
+
+#[rpc(client, server)]
+pub trait AirdropClaimQueueApi {
+	/// Queue a user claim to be relayed by offchain worker of this node.
+	/// Ice signature is either raw 64 bytes sr25519 signature or
+	/// scale encoded `MultiSignature` for ed25519 and ecdsa.
+	/// Claim is dropped if not relayed by `expiry` block or once
+	/// signed message is older than what runtime accept.
+	/// Relayed claims are paid by the server account so this is
+	/// an unsafe method only available to the node operator
+	#[method(name = "airdrop_submitClaim")]
+	fn submit_claim(
+		&self,
+		campaign_id: u32,
+		icon_address: H160,
+		ice_address: H256,
+		message: Bytes,
+		icon_signature: Bytes,
+		ice_signature: Bytes,
+		amount: NumberOrHex,
+		defi_user: bool,
+		proofs: Vec<H256>,
+		expiry: u32,
+	) -> RpcResult<()>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// Runtime refused to process the request.
+	RequestRejected,
+	/// Given amount do not fit into balance type.
+	InvalidAmount,
+	/// Given claim is not well formed.
+	InvalidClaim,
+	/// Offchain claim queue can't take more claims.
+	QueueFull,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::RequestRejected => 2,
+			Error::InvalidAmount => 3,
+			Error::InvalidClaim => 4,
+			Error::QueueFull => 5,
+		}
+	}
+}
+
+/// Provides RPC methods to query airdrop claims.
+pub struct Airdrop<C, P> {
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> Airdrop<C, P> {
+	/// Creates a new instance of the Airdrop Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+	AirdropApiServer<
+		<Block as BlockT>::Hash,
+		ClaimPreview<AccountId, Balance, BlockNumber>,
+		Balance,
+		PartialSnapshotsPage<AccountId, Balance, BlockNumber>,
+	> for Airdrop<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AirdropRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	AccountId: Codec + serde::Serialize + Send + Sync + 'static,
+	Balance: Codec + TryFrom<NumberOrHex> + serde::Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + serde::Serialize + Send + Sync + 'static,
+{
+	fn preview_claim(
+		&self,
+		campaign_id: u32,
+		icon_address: H160,
+		amount: NumberOrHex,
+		defi_user: bool,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ClaimPreview<AccountId, Balance, BlockNumber>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let amount = to_balance(amount)?;
+
+		let preview = api
+			.preview_claim(&at, campaign_id, icon_address.0, amount, defi_user)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query claim preview.",
+					Some(e.to_string()),
+				))
+			})?;
+
+		preview.map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RequestRejected.into(),
+				"Unable to preview claim.",
+				Some(format!("{:?}", e)),
+			))
+			.into()
+		})
+	}
+
+	fn verify_merkle_proof(
+		&self,
+		campaign_id: u32,
+		icon_address: H160,
+		amount: NumberOrHex,
+		defi_user: bool,
+		proofs: Vec<H256>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<MerkleProofCheck> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let amount = to_balance(amount)?;
+		let proofs = proofs.into_iter().map(|proof| proof.0).collect();
+
+		let check = api
+			.verify_merkle_proof(&at, campaign_id, icon_address.0, amount, defi_user, proofs)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to verify merkle proof.",
+					Some(e.to_string()),
+				))
+			})?;
+
+		check.map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RequestRejected.into(),
+				"Unable to verify merkle proof.",
+				Some(format!("{:?}", e)),
+			))
+			.into()
+		})
+	}
+
+	fn pending_exchange_claims(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PendingExchangeClaim<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.pending_exchange_claims(&at).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query pending exchange claims.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+
+	fn partial_snapshots(
+		&self,
+		start_key: Option<SnapshotKey>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<PartialSnapshotsPage<AccountId, Balance, BlockNumber>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.partial_snapshots(&at, start_key, limit).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query partial snapshots.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+}
+
+/// Provides RPC method to queue claims for offchain worker.
+pub struct AirdropClaimQueue<S> {
+	storage: Arc<Mutex<S>>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<S: OffchainStorage> AirdropClaimQueue<S> {
+	/// Creates a new instance writing into given offchain storage.
+	pub fn new(storage: S, deny_unsafe: DenyUnsafe) -> Self {
+		Self {
+			storage: Arc::new(Mutex::new(storage)),
+			deny_unsafe,
+		}
+	}
+}
+
+impl<S> AirdropClaimQueueApiServer for AirdropClaimQueue<S>
+where
+	S: OffchainStorage + 'static,
+{
+	fn submit_claim(
+		&self,
+		campaign_id: u32,
+		icon_address: H160,
+		ice_address: H256,
+		message: Bytes,
+		icon_signature: Bytes,
+		ice_signature: Bytes,
+		amount: NumberOrHex,
+		defi_user: bool,
+		proofs: Vec<H256>,
+		expiry: u32,
+	) -> RpcResult<()> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let invalid_claim = |reason: &str| {
+			CallError::Custom(ErrorObject::owned(
+				Error::InvalidClaim.into(),
+				"Claim is not well formed.",
+				Some(reason.to_string()),
+			))
+		};
+
+		let claim = QueuedClaim {
+			campaign_id,
+			icon_address: icon_address.0,
+			ice_address: ice_address.0,
+			message: message.0,
+			icon_signature: icon_signature
+				.0
+				.try_into()
+				.map_err(|_| invalid_claim("Icon signature must be 65 bytes"))?,
+			ice_signature: decode_ice_signature(ice_signature.0)
+				.ok_or_else(|| invalid_claim("Ice signature is not a valid signature"))?,
+			amount: to_balance(amount)?,
+			defi_user,
+			proofs: proofs.into_iter().map(|proof| proof.0).collect(),
+			expiry,
+		};
+
+		let mut storage = self.storage.lock().map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Offchain storage is not accessible.",
+				Some(e.to_string()),
+			))
+		})?;
+
+		// Offchain worker may take from queue in between so
+		// only write if queue is still what we have read
+		loop {
+			let current = storage.get(sp_offchain::STORAGE_PREFIX, CLAIM_QUEUE_KEY);
+			let mut queue = current
+				.as_ref()
+				.and_then(|raw| Vec::<QueuedClaim>::decode(&mut &raw[..]).ok())
+				.unwrap_or_default();
+
+			if queue.len() >= MAX_QUEUED_CLAIMS {
+				return Err(CallError::Custom(ErrorObject::owned(
+					Error::QueueFull.into(),
+					"Offchain claim queue is full.",
+					None::<()>,
+				))
+				.into());
+			}
+			queue.push(claim.clone());
+
+			let is_written = storage.compare_and_set(
+				sp_offchain::STORAGE_PREFIX,
+				CLAIM_QUEUE_KEY,
+				current.as_deref(),
+				&queue.encode(),
+			);
+			if is_written {
+				return Ok(());
+			}
+		}
+	}
+}
+
+fn to_balance<Balance: TryFrom<NumberOrHex>>(amount: NumberOrHex) -> Result<Balance, CallError> {
+	Balance::try_from(amount).map_err(|_| {
+		CallError::Custom(ErrorObject::owned(
+			Error::InvalidAmount.into(),
+			"Amount do not fit into balance type.",
+			None::<()>,
+		))
+	})
+}
+
+/// Raw 64 bytes are sr25519 signature as accepted before other
+/// schemes were supported. Anything else is scale encoded `MultiSignature`
+fn decode_ice_signature(raw: Vec<u8>) -> Option<MultiSignature> {
+	if let Ok(raw) = <[u8; 64]>::try_from(raw.as_slice()) {
+		return Some(sp_core::sr25519::Signature::from_raw(raw).into());
+	}
+	MultiSignature::decode(&mut &raw[..]).ok()
+}
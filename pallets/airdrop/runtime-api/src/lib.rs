@@ -0,0 +1,202 @@
+//! Runtime API definition for the airdrop pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Vesting schedule that is applied to the vesting part of a claim
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct VestingSchedulePreview<Balance, BlockNumber> {
+	/// Total amount locked in this schedule
+	pub locked: Balance,
+
+	/// Amount unlocked on every block
+	pub per_block: Balance,
+
+	/// Block from where unlocking starts
+	pub starting_block: BlockNumber,
+}
+
+/// Claim information as stored on chain for an icon address
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SnapshotPreview<AccountId, Balance, BlockNumber> {
+	pub ice_address: AccountId,
+	pub amount: Balance,
+	pub defi_user: bool,
+	pub done_instant: bool,
+	pub done_vesting: bool,
+	pub vesting_block_number: Option<BlockNumber>,
+	pub instant_block_number: Option<BlockNumber>,
+	pub initial_transfer: Balance,
+
+	/// Instant percentage that was used to split the amount
+	pub instant_percentage: Option<u8>,
+
+	/// Vesting period that was used to split the amount
+	pub vesting_period: Option<u32>,
+}
+
+/// Outcome of a claim without actually making it
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimPreview<AccountId, Balance, BlockNumber> {
+	/// Amount transferred instantly as per instant percentage
+	pub instant_amount: Balance,
+
+	/// Schedule applied to remaining amount. None when
+	/// remaining amount is too small to be vested
+	pub vesting_schedule: Option<VestingSchedulePreview<Balance, BlockNumber>>,
+
+	/// Part of vesting amount that can't be divided per block.
+	/// This is transferred instantly along with instant_amount
+	pub remainder: Balance,
+
+	/// Whether claim for this icon address is already completed
+	pub already_claimed: bool,
+
+	/// Snapshot stored for this icon address if any
+	pub snapshot: Option<SnapshotPreview<AccountId, Balance, BlockNumber>>,
+}
+
+/// Values computed while verifying a merkle proof
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MerkleProofCheck {
+	/// Hash of the leaf built from icon address, amount and defi flag
+	pub leaf_hash: H256,
+
+	/// Root reconstructed by applying given proofs to leaf hash
+	pub computed_root: H256,
+
+	/// Root stored on chain for the campaign
+	pub stored_root: H256,
+
+	/// Whether computed root matches the stored root
+	pub is_valid: bool,
+}
+
+/// Exchange account that have not made it's claim yet
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PendingExchangeClaim<Balance> {
+	/// Icon address of the exchange
+	pub icon_address: H160,
+
+	/// Amount exchange is allowed to claim
+	pub amount: Balance,
+}
+
+/// Snapshot whose instant transfer is done but vesting is not
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PartialSnapshot<AccountId, Balance, BlockNumber> {
+	/// Campaign this snapshot belongs to
+	pub campaign_id: u32,
+
+	/// Icon address that made the claim
+	pub icon_address: H160,
+
+	/// Snapshot as stored on chain
+	pub snapshot: SnapshotPreview<AccountId, Balance, BlockNumber>,
+}
+
+/// Position of a snapshot in storage from where listing can continue
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SnapshotKey {
+	pub campaign_id: u32,
+	pub icon_address: H160,
+}
+
+/// Partial snapshots found among a bounded number of scanned snapshots
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PartialSnapshotsPage<AccountId, Balance, BlockNumber> {
+	pub snapshots: Vec<PartialSnapshot<AccountId, Balance, BlockNumber>>,
+
+	/// Key to pass as start of next page. None once all snapshots
+	/// have been scanned
+	pub next_key: Option<SnapshotKey>,
+}
+
+/// Maximum number of snapshots scanned for a single page of partial snapshots
+pub const MAX_SCANNED_SNAPSHOTS: u32 = 1000;
+
+/// Key in persistent offchain storage under which claims submitted
+/// through rpc are queued for offchain worker
+pub const CLAIM_QUEUE_KEY: &[u8] = b"airdrop::claim-queue";
+
+/// Maximum number of claims that can wait in offchain queue
+pub const MAX_QUEUED_CLAIMS: usize = 1024;
+
+/// User claim waiting in offchain queue to be relayed by offchain worker
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct QueuedClaim {
+	pub campaign_id: u32,
+	pub icon_address: [u8; 20],
+	pub ice_address: [u8; 32],
+
+	/// Raw icx_sendTransaction payload signed by icon address
+	pub message: Vec<u8>,
+	pub icon_signature: [u8; 65],
+	pub ice_signature: sp_runtime::MultiSignature,
+	pub amount: u128,
+	pub defi_user: bool,
+	pub proofs: Vec<[u8; 32]>,
+
+	/// Last block in which claim can be made
+	pub expiry: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait AirdropApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Preview the outcome of claiming given amount in given campaign
+		fn preview_claim(
+			campaign_id: u32,
+			icon_address: [u8; 20],
+			amount: Balance,
+			defi_user: bool,
+		) -> Result<ClaimPreview<AccountId, Balance, BlockNumber>, DispatchError>;
+
+		/// Verify given merkle proof against root of given campaign
+		/// and return the intermediate hashes
+		fn verify_merkle_proof(
+			campaign_id: u32,
+			icon_address: [u8; 20],
+			amount: Balance,
+			defi_user: bool,
+			proofs: Vec<[u8; 32]>,
+		) -> Result<MerkleProofCheck, DispatchError>;
+
+		/// List exchange accounts whose claim is not settled yet
+		fn pending_exchange_claims() -> Vec<PendingExchangeClaim<Balance>>;
+
+		/// List snapshots that are left partially claimed among at most
+		/// `limit` snapshots stored after `start_key`
+		fn partial_snapshots(
+			start_key: Option<SnapshotKey>,
+			limit: u32,
+		) -> PartialSnapshotsPage<AccountId, Balance, BlockNumber>;
+	}
+}
@@ -7,15 +7,20 @@ use crate as pallet_airdrop;
 use crate::Pallet;
 use codec::Decode;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
-use frame_support::{pallet_prelude::*, traits::ConstU32, BoundedVec};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{ConstU32, Currency},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_core::crypto::KeyTypeId;
 use sp_core::sr25519;
 use sp_core::*;
-use sp_runtime::traits::Convert;
 use sp_runtime::traits::IdentifyAccount;
 use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{Bounded, Convert};
 use sp_std::prelude::*;
-use types::{AccountIdOf, BlockNumberOf, IceAddress, MerkleHash, RawPayload};
+use types::{AccountIdOf, BlockNumberOf, IceAddress, MerkleHash};
 
 #[derive(Clone, Debug)]
 pub struct BenchmarkSample<'a> {
@@ -34,7 +39,7 @@ pub struct BenchmarkSample<'a> {
 pub struct UserClaimTestCase<T: Get<u32>> {
 	pub icon_address: types::IconAddress,
 	pub ice_address: IceAddress,
-	pub message: RawPayload,
+	pub message: Vec<u8>,
 	pub icon_signature: types::IconSignature,
 	pub ice_signature: types::IceSignature,
 	pub amount: u128,
@@ -53,7 +58,7 @@ where
 		let defi_user = sample.defi_user;
 		let ice_address = hex::decode(sample.ice_address).unwrap().try_into().unwrap();
 		let merkle_root = hex::decode(sample.merkle_root).unwrap().try_into().unwrap();
-		let message = sample.message.as_bytes().to_vec().try_into().unwrap();
+		let message = sample.message.as_bytes().to_vec();
 		let icon_address = hex::decode(sample.icon_address)
 			.unwrap()
 			.try_into()
@@ -62,10 +67,13 @@ where
 			.unwrap()
 			.try_into()
 			.unwrap();
-		let ice_signature = hex::decode(sample.ice_signature)
-			.unwrap()
-			.try_into()
-			.unwrap();
+		let ice_signature = sr25519::Signature::from_raw(
+			hex::decode(sample.ice_signature)
+				.unwrap()
+				.try_into()
+				.unwrap(),
+		)
+		.into();
 		let merkle_proofs = sample
 			.merkle_proofs
 			.iter()
@@ -184,10 +192,156 @@ pub const BENCHMARK_SAMPLES: [BenchmarkSample; 4] = [
 
 const CREDITOR_KEY: sr25519::Public = sr25519::Public([1; 32]);
 
+// Split some amount into vesting so that worst case is benchmarked
+const VESTING_TERMS: types::VestingTerms = types::VestingTerms {
+	defi_instant_percentage: 40,
+	non_defi_instant_percentage: 30,
+	vesting_period: 5_256_000,
+};
+
+// Claim to evm address 1563915e194d8cfba1943570603f7606a3115508
+// signed by icon address 396031be52ec56955bd7bf15eacdfa1a1c1fe19e
+const EVM_CLAIM_ICON_ADDRESS: types::IconAddress =
+	hex_literal::hex!("396031be52ec56955bd7bf15eacdfa1a1c1fe19e");
+const EVM_CLAIM_ADDRESS: [u8; 20] = hex_literal::hex!("1563915e194d8cfba1943570603f7606a3115508");
+const EVM_CLAIM_MESSAGE: &[u8] = b"icx_sendTransaction.data.{method.transfer.params.{wallet.1563915e194d8cfba1943570603f7606a3115508}}.dataType.call.from.hx396031be52ec56955bd7bf15eacdfa1a1c1fe19e.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hx396031be52ec56955bd7bf15eacdfa1a1c1fe19e.version.0x3";
+const EVM_CLAIM_ICON_SIGNATURE: types::IconSignature = hex_literal::hex!("ec6d499aefd540e90357f1004a136049d1f7df5ad99c44c46e3ed4169e40acb67a7ce82cede20e9d916f2eb0fe80fb3406d40d0d89747d744263921f1fd1193200");
+const EVM_CLAIM_SIGNATURE: types::IceEvmSignature = hex_literal::hex!("71550e6c83a9381f35c568d1a80e11fa3e0efc97dfd0e0f17492a2edb64c37a94ed3692250f390c4c0446251d4f15d549846583cee73beaacb10f1aa9046e54901");
+
+// Ice signatures over EVM_CLAIM_ICON_SIGNATURE made with
+// ed25519 and ecdsa keys generated from seed [2; 32] and [3; 32]
+const ED25519_ICE_ADDRESS: IceAddress =
+	hex_literal::hex!("8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394");
+const ED25519_ICE_SIGNATURE: [u8; 64] = hex_literal::hex!("ebf7d68840f0c89b098584065bffb2d42d3157cebd67a484a752fc2695bf38ee5c5eb69024f756c598b4c6298c4c9ec04b060dd8b9a90753430ccaf3c033e202");
+// Blake2 hash of compressed ecdsa public key
+const ECDSA_ICE_ADDRESS: IceAddress =
+	hex_literal::hex!("5543276c91fa084d408404227a7b9fbfff1b5b396aa305adbd61158839f964ed");
+const ECDSA_ICE_SIGNATURE: [u8; 65] = hex_literal::hex!("855d9f32bf06a22bffdc74aba32525872d0311d1ecb0795ecd6cf5136ebe9206116bcb333327d051855ccdd536d5795ce49b29b2f605c16c49f123d8a774a25800");
+
+/// Key type under which keys of generated claims are kept in benchmark keystore
+const CLAIM_KEY_TYPE: KeyTypeId = KeyTypeId(*b"aird");
+
+fn sha3_256(data: &[u8]) -> [u8; 32] {
+	use fp_evm::LinearCostPrecompile;
+	use pallet_evm_precompile_sha3fips::Sha3FIPS256;
+
+	let (_exit_status, hash) = Sha3FIPS256::execute(data, 1).unwrap();
+	hash.try_into().unwrap()
+}
+
+/// Icon address of secp256k1 key in keystore. Public key is recovered
+/// from a signature as keystore only expose the compressed one
+fn icon_address_of(public: &ecdsa::Public) -> types::IconAddress {
+	let hash = [0u8; 32];
+	let signature = sp_io::crypto::ecdsa_sign_prehashed(CLAIM_KEY_TYPE, public, &hash).unwrap();
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &hash).unwrap();
+	sha3_256(&public)[12..].try_into().unwrap()
+}
+
+/// Claim of given amount signed with freshly generated icon and ice keys.
+/// Unlike `BENCHMARK_SAMPLES` any number of claims can be made this way.
+/// Merkle proof is left empty, see `set_merkle_proofs`
+fn generate_claim<B: Get<u32>>(amount: u128) -> UserClaimTestCase<B> {
+	let icon_public = sp_io::crypto::ecdsa_generate(CLAIM_KEY_TYPE, None);
+	let ice_public = sp_io::crypto::sr25519_generate(CLAIM_KEY_TYPE, None);
+	let icon_address = icon_address_of(&icon_public);
+
+	let icon_hex = hex::encode(icon_address);
+	let message = codec::alloc::format!(
+		"icx_sendTransaction.data.{{method.transfer.params.{{wallet.{}}}}}.dataType.call.from.hx{icon_hex}.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hx{icon_hex}.version.0x3",
+		hex::encode(ice_public),
+	)
+	.into_bytes();
+	let icon_signature =
+		sp_io::crypto::ecdsa_sign_prehashed(CLAIM_KEY_TYPE, &icon_public, &sha3_256(&message))
+			.unwrap()
+			.0;
+	let ice_signature = sp_io::crypto::sr25519_sign(
+		CLAIM_KEY_TYPE,
+		&ice_public,
+		&utils::wrap_bytes(&icon_signature),
+	)
+	.unwrap()
+	.into();
+
+	UserClaimTestCase::<B> {
+		icon_address,
+		ice_address: ice_public.0,
+		message,
+		icon_signature,
+		ice_signature,
+		amount,
+		defi_user: true,
+		merkle_proofs: BoundedVec::default(),
+		merkle_root: [0u8; 32],
+	}
+}
+
+/// Put all given claims under a single merkle root and return it.
+/// Tree is padded so that every proof is `MaxProofSize` long
+/// i.e each claim cost the most to verify
+fn set_merkle_proofs<T: Config>(cases: &mut [UserClaimTestCase<T::MaxProofSize>]) -> [u8; 32] {
+	let mut layer = cases
+		.iter()
+		.map(|case| {
+			merkle::hash_leaf::<T::MerkleLeafEncoder, T::MerkleNodeHasher>(
+				&case.icon_address,
+				case.amount,
+				case.defi_user,
+			)
+		})
+		.collect::<Vec<MerkleHash>>();
+	let mut positions = (0..cases.len()).collect::<Vec<_>>();
+	let mut proofs = sp_std::vec![Vec::<MerkleHash>::new(); cases.len()];
+	let mut filler = 0_u8;
+
+	while layer.len() > 1 || proofs[0].len() < T::MaxProofSize::get() as usize {
+		// Node without sibling is paired with a filler
+		if layer.len() % 2 == 1 {
+			filler += 1;
+			layer.push([filler; 32]);
+		}
+		for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+			proof.push(layer[*position ^ 1]);
+			*position /= 2;
+		}
+		layer = layer
+			.chunks(2)
+			.map(|pair| merkle::create_hash::<T::MerkleNodeHasher>(pair[0], pair[1]))
+			.collect();
+	}
+
+	for (case, proof) in cases.iter_mut().zip(proofs) {
+		case.merkle_proofs = proof.try_into().unwrap();
+	}
+	layer[0]
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Make whitelisted caller the server account and return it
+/// so that privileged calls also pay for origin check
+fn init_server<T: Config>() -> AccountIdOf<T> {
+	let server: AccountIdOf<T> = whitelisted_caller();
+	<ServerAccount<T>>::set(Some(server.clone()));
+	server
+}
+
+/// Watch creditor of given campaign against a low water mark it is
+/// always below so that every transfer also flags the creditor as low
+fn init_creditor_funding<T: Config>(campaign_id: types::CampaignId) {
+	<CreditorFunding<T>>::insert(
+		campaign_id,
+		types::CreditorFundingInfo::<T> {
+			low_water_mark: Bounded::max_value(),
+			refill_amount: 0_u32.into(),
+			allowance: 0_u32.into(),
+		},
+	);
+}
+
 benchmarks! {
 	set_airdrop_server_account {
 				let old_account: types::AccountIdOf<T> = frame_benchmarking::whitelisted_caller();
@@ -220,37 +374,76 @@ benchmarks! {
 	dispatch_user_claim {
 		let caller: types::AccountIdOf<T> = frame_benchmarking::whitelisted_caller();
 		// let ofw_account = sr25519::Public([1; 32]).into_account();
-		Pallet::<T>::set_creditor_account(CREDITOR_KEY);
-		let system_account_id = Pallet::<T>::get_creditor_account().unwrap();
-		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
 		let case= UserClaimTestCase::<<T as pallet::Config>::MaxProofSize>::try_from(BENCHMARK_SAMPLES[1].clone()).unwrap();
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, case.merkle_root, VESTING_TERMS);
+		let system_account_id = Pallet::<T>::get_creditor_account(0).unwrap();
+		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
 		let amount = <T::BalanceTypeConversion as Convert<_, _>>::convert(case.amount);
 		 let icon_address=case.icon_address.clone();
 		 let mut new_state = types::AirdropState::default();
 		 new_state.block_claim_request=false;
 		 new_state.block_exchange_request=false;
 		<AirdropChainState<T>>::set(new_state.clone());
+		let message = <types::RawPayload<T>>::try_from(case.message.clone())
+			.map_err(|_| "Benchmark samples are icon payload")?;
+		let server = init_server::<T>();
+		init_creditor_funding::<T>(0);
 
 	}: dispatch_user_claim(
-		RawOrigin::Root,
+		RawOrigin::Signed(server),
+		0,
 		case.icon_address,
 		case.ice_address,
-		case.message,
+		message,
 		case.icon_signature,
 		case.ice_signature,
 		amount,
 		case.defi_user,
-		case.merkle_proofs)
+		case.merkle_proofs,
+		BlockNumberOf::<T>::max_value())
+	verify {
+		let snapshot = <IconSnapshotMap<T>>::get(0, icon_address).unwrap();
+		assert!(snapshot.done_instant && snapshot.done_vesting);
+	}
+
+	dispatch_evm_user_claim {
+		let amount: types::ServerBalance = 1_000_000_000_000_000_000;
+		// Without any proof leaf itself is the root
+		let merkle_root = merkle::hash_leaf::<T::MerkleLeafEncoder, T::MerkleNodeHasher>(&EVM_CLAIM_ICON_ADDRESS, amount, true);
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, merkle_root, VESTING_TERMS);
+		let system_account_id = Pallet::<T>::get_creditor_account(0).unwrap();
+		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
+		let amount = <T::BalanceTypeConversion as Convert<_, _>>::convert(amount);
+		let mut new_state = types::AirdropState::default();
+		new_state.block_claim_request=false;
+		<AirdropChainState<T>>::set(new_state);
+		let message = <types::EvmRawPayload<T>>::try_from(EVM_CLAIM_MESSAGE.to_vec())
+			.map_err(|_| "Benchmark samples are icon payload")?;
+		let server = init_server::<T>();
+		init_creditor_funding::<T>(0);
+	}: dispatch_evm_user_claim(
+		RawOrigin::Signed(server),
+		0,
+		EVM_CLAIM_ICON_ADDRESS,
+		EVM_CLAIM_ADDRESS.into(),
+		message,
+		EVM_CLAIM_ICON_SIGNATURE,
+		EVM_CLAIM_SIGNATURE,
+		amount,
+		true,
+		BoundedVec::default(),
+		BlockNumberOf::<T>::max_value())
 	verify {
-		assert_last_event::<T>(Event::ClaimSuccess(icon_address.clone()).into());
+		let snapshot = <IconSnapshotMap<T>>::get(0, EVM_CLAIM_ICON_ADDRESS).unwrap();
+		assert!(snapshot.done_instant && snapshot.done_vesting);
 	}
 
 	dispatch_exchange_claim {
 
-		Pallet::<T>::set_creditor_account(CREDITOR_KEY);
-		let system_account_id = Pallet::<T>::get_creditor_account().unwrap();
-		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
 		let case= UserClaimTestCase::<<T as pallet::Config>::MaxProofSize>::try_from(BENCHMARK_SAMPLES[0].clone()).unwrap();
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, case.merkle_root, VESTING_TERMS);
+		let system_account_id = Pallet::<T>::get_creditor_account(0).unwrap();
+		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
 		let amount = <T::BalanceTypeConversion as Convert<_, _>>::convert(case.amount);
 		let icon_address=case.icon_address.clone();
 		<ExchangeAccountsMap<T>>::insert(icon_address.clone(),amount);
@@ -262,28 +455,290 @@ benchmarks! {
 
 	}: dispatch_exchange_claim(
 		RawOrigin::Root,
+		0,
 		icon_address.clone(),
 		case.ice_address,
 		amount,
 		case.defi_user,
 		case.merkle_proofs)
 	verify {
-		assert_last_event::<T>(Event::ClaimSuccess(icon_address.clone()).into());
+		let snapshot = <IconSnapshotMap<T>>::get(0, icon_address).unwrap();
+		assert!(snapshot.done_instant && snapshot.done_vesting);
+	}
+
+	dispatch_user_claims_batch {
+		let n in 1 .. T::MaxClaimBatchSize::get();
+
+		let mut cases = (0..n)
+			.map(|i| generate_claim::<T::MaxProofSize>((i as u128 + 1) * 1_000_000_000_000_000_000))
+			.collect::<Vec<_>>();
+		let merkle_root = set_merkle_proofs::<T>(&mut cases);
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, merkle_root, VESTING_TERMS);
+		let system_account_id = Pallet::<T>::get_creditor_account(0).unwrap();
+		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
+		let mut new_state = types::AirdropState::default();
+		new_state.block_claim_request=false;
+		new_state.block_exchange_request=false;
+		<AirdropChainState<T>>::set(new_state.clone());
+
+		let server = init_server::<T>();
+		init_creditor_funding::<T>(0);
+
+		let mut claims = Vec::new();
+		for case in cases {
+			claims.push(types::UserClaimRequest::<T> {
+				campaign_id: 0,
+				icon_address: case.icon_address,
+				ice_address: case.ice_address,
+				message: <types::RawPayload<T>>::try_from(case.message)
+					.map_err(|_| "Benchmark samples are icon payload")?,
+				icon_signature: case.icon_signature,
+				ice_signature: case.ice_signature,
+				amount: <T::BalanceTypeConversion as Convert<_, _>>::convert(case.amount),
+				defi_user: case.defi_user,
+				proofs: case.merkle_proofs,
+				expiry: BlockNumberOf::<T>::max_value(),
+			});
+		}
+		let claims: types::UserClaimBatch<T> = claims.try_into().unwrap();
+
+	}: dispatch_user_claims_batch(RawOrigin::Signed(server), claims)
+	verify {
+		assert_last_event::<T>(Event::ClaimBatchProcessed {
+			succeeded: n,
+			failed: 0,
+		}.into());
 	}
 
 	change_merkle_root {
 		let new_root = [1 as u8;32];
 		let last_root = [0u8;32];
-		MerkleRoot::<T>::put(last_root.clone());
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, last_root.clone(), VESTING_TERMS);
 	}: change_merkle_root(
 		RawOrigin::Root,
+		0,
 		new_root
 	) verify {
 		assert_last_event::<T>(Event::MerkleRootUpdated{
-			old_root: Some(last_root),
+			campaign_id: 0,
+			old_root: last_root,
 			new_root,
 		}.into());
 	}
 
-	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+	create_campaign {
+		let merkle_root = [1 as u8;32];
+		let creditor: T::AccountId = account("creditor", 0, 0);
+		let campaign_id = Pallet::<T>::get_next_campaign_id();
+	}: create_campaign(
+		RawOrigin::Root,
+		merkle_root,
+		creditor,
+		VESTING_TERMS,
+		0_u32.into(),
+		None
+	) verify {
+		assert_last_event::<T>(Event::CampaignCreated{
+			campaign_id,
+			merkle_root,
+		}.into());
+	}
+
+	update_campaign_state {
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, [0u8; 32], VESTING_TERMS);
+	}: update_campaign_state(
+		RawOrigin::Root,
+		0,
+		types::CampaignState::Closed
+	) verify {
+		assert_last_event::<T>(Event::CampaignStateUpdated{
+			campaign_id: 0,
+			old_state: types::CampaignState::Open,
+			new_state: types::CampaignState::Closed,
+		}.into());
+	}
+
+	set_vesting_terms {
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, [0u8; 32], VESTING_TERMS);
+		let new_terms = types::VestingTerms {
+			defi_instant_percentage: 100,
+			non_defi_instant_percentage: 50,
+			vesting_period: 100,
+		};
+	}: set_vesting_terms(
+		RawOrigin::Root,
+		0,
+		new_terms
+	) verify {
+		assert_last_event::<T>(Event::VestingTermsUpdated{
+			campaign_id: 0,
+			old_terms: VESTING_TERMS,
+			new_terms,
+		}.into());
+	}
+
+	set_claim_deadline {
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, [0u8; 32], VESTING_TERMS);
+		let new_deadline: BlockNumberOf<T> = 100_u32.into();
+	}: set_claim_deadline(
+		RawOrigin::Root,
+		0,
+		Some(new_deadline)
+	) verify {
+		assert_last_event::<T>(Event::ClaimDeadlineUpdated{
+			campaign_id: 0,
+			old_deadline: None,
+			new_deadline: Some(new_deadline),
+		}.into());
+	}
+
+	sweep_unclaimed_funds {
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, [0u8; 32], VESTING_TERMS);
+		<Campaigns<T>>::mutate(0, |campaign| {
+			campaign.as_mut().unwrap().end_block = Some(10_u32.into());
+		});
+		frame_system::Pallet::<T>::set_block_number(11_u32.into());
+
+		let creditor = Pallet::<T>::get_creditor_account(0).unwrap();
+		Pallet::<T>::init_balance(&creditor, 10_000_000_000_000_000_000_000_000);
+		let swept = <T as Config>::Currency::free_balance(&creditor)
+			.saturating_sub(<T as Config>::Currency::minimum_balance());
+	}: sweep_unclaimed_funds(
+		RawOrigin::Root,
+		0
+	) verify {
+		assert_last_event::<T>(Event::UnclaimedFundsSwept{
+			campaign_id: 0,
+			beneficiary: T::UnclaimedFundsBeneficiary::get(),
+			distributed: 0_u32.into(),
+			swept,
+		}.into());
+	}
+
+	add_exchange_account {
+		let icon_address = [1u8; 20];
+		let amount: types::BalanceOf<T> = 10_000_u32.into();
+	}: add_exchange_account(
+		RawOrigin::Root,
+		icon_address,
+		amount
+	) verify {
+		assert_last_event::<T>(Event::ExchangeAccountAdded{
+			icon_address,
+			amount,
+		}.into());
+	}
+
+	update_exchange_account {
+		let icon_address = [1u8; 20];
+		let old_amount: types::BalanceOf<T> = 10_000_u32.into();
+		let new_amount: types::BalanceOf<T> = 20_000_u32.into();
+		<ExchangeAccountsMap<T>>::insert(icon_address, old_amount);
+	}: update_exchange_account(
+		RawOrigin::Root,
+		icon_address,
+		new_amount
+	) verify {
+		assert_last_event::<T>(Event::ExchangeAccountUpdated{
+			icon_address,
+			old_amount,
+			new_amount,
+		}.into());
+	}
+
+	remove_exchange_account {
+		let icon_address = [1u8; 20];
+		let amount: types::BalanceOf<T> = 10_000_u32.into();
+		<ExchangeAccountsMap<T>>::insert(icon_address, amount);
+	}: remove_exchange_account(
+		RawOrigin::Root,
+		icon_address
+	) verify {
+		assert_last_event::<T>(Event::ExchangeAccountRemoved{
+			icon_address,
+			amount,
+		}.into());
+	}
+
+	complete_partial_claim {
+		let case= UserClaimTestCase::<<T as pallet::Config>::MaxProofSize>::try_from(BENCHMARK_SAMPLES[0].clone()).unwrap();
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, case.merkle_root, VESTING_TERMS);
+		let system_account_id = Pallet::<T>::get_creditor_account(0).unwrap();
+		Pallet::<T>::init_balance(&system_account_id,10_000_000_000_000_000_000_000_000);
+		let amount = <T::BalanceTypeConversion as Convert<_, _>>::convert(case.amount);
+		let ice_account = Pallet::<T>::convert_to_account_id(case.ice_address).unwrap();
+
+		// Instant transfer is done but vesting is not
+		let (instant_amount, _schedule, remainder) =
+			transfer::split_amount::<T>(amount, case.defi_user, &VESTING_TERMS).unwrap();
+		let mut snapshot = types::SnapshotInfo::<T>::new(ice_account.clone(), case.defi_user, amount);
+		snapshot.done_instant = true;
+		snapshot.initial_transfer = instant_amount.saturating_add(remainder);
+		snapshot.vesting_terms = Some(VESTING_TERMS);
+		<T as Config>::Currency::make_free_balance_be(&ice_account, snapshot.initial_transfer);
+		<IconSnapshotMap<T>>::insert(0, case.icon_address, snapshot);
+
+		let mut new_state = types::AirdropState::default();
+		new_state.block_claim_request=false;
+		<AirdropChainState<T>>::set(new_state);
+		let server = init_server::<T>();
+		init_creditor_funding::<T>(0);
+	}: complete_partial_claim(
+		RawOrigin::Signed(server),
+		0,
+		case.icon_address
+	) verify {
+		let snapshot = <IconSnapshotMap<T>>::get(0, case.icon_address).unwrap();
+		assert!(snapshot.done_instant && snapshot.done_vesting);
+	}
+
+	set_creditor_funding {
+		Pallet::<T>::init_campaign(0, CREDITOR_KEY, [0u8; 32], VESTING_TERMS);
+		let funding = types::CreditorFundingInfo::<T> {
+			low_water_mark: 10_000_u32.into(),
+			refill_amount: 20_000_u32.into(),
+			allowance: 100_000_u32.into(),
+		};
+	}: set_creditor_funding(
+		RawOrigin::Root,
+		0,
+		Some(funding.clone())
+	) verify {
+		assert_last_event::<T>(Event::CreditorFundingUpdated{
+			campaign_id: 0,
+			funding: Some(funding),
+		}.into());
+	}
+
+	revoke_icon_address {
+		let icon_address = EVM_CLAIM_ICON_ADDRESS;
+		let server = init_server::<T>();
+	}: revoke_icon_address(
+		RawOrigin::Signed(server),
+		icon_address
+	) verify {
+		assert_last_event::<T>(Event::IconAddressRevoked{
+			icon_address,
+		}.into());
+	}
+
+	validate_ice_signature_sr25519 {
+		let case= UserClaimTestCase::<<T as pallet::Config>::MaxProofSize>::try_from(BENCHMARK_SAMPLES[1].clone()).unwrap();
+	}: {
+		assert!(Pallet::<T>::validate_ice_signature(&case.ice_signature, &case.icon_signature, &case.ice_address).is_ok());
+	}
+
+	validate_ice_signature_ed25519 {
+		let signature: types::IceSignature = ed25519::Signature::from_raw(ED25519_ICE_SIGNATURE).into();
+	}: {
+		assert!(Pallet::<T>::validate_ice_signature(&signature, &EVM_CLAIM_ICON_SIGNATURE, &ED25519_ICE_ADDRESS).is_ok());
+	}
+
+	validate_ice_signature_ecdsa {
+		let signature: types::IceSignature = ecdsa::Signature::from_raw(ECDSA_ICE_SIGNATURE).into();
+	}: {
+		assert!(Pallet::<T>::validate_ice_signature(&signature, &EVM_CLAIM_ICON_SIGNATURE, &ECDSA_ICE_ADDRESS).is_ok());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
 }
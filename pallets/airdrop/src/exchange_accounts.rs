@@ -21,16 +21,15 @@ pub(crate) const EXCHANGE_ACCOUNTS: &[([u8; 20], u128)] = &[
 	),
 ];
 
-use crate::{types, Config};
-use sp_runtime::traits::Convert;
+use crate::{types, Config, Error};
 use sp_std::vec::Vec;
-pub(crate) fn get_exchange_account<T: Config>() -> Vec<(types::IconAddress, types::BalanceOf<T>)> {
+
+/// Exchange accounts with balance converted into runtime balance.
+/// Fails if any of the balance do not fit
+pub(crate) fn get_exchange_account<T: Config>(
+) -> Result<Vec<(types::IconAddress, types::BalanceOf<T>)>, Error<T>> {
 	EXCHANGE_ACCOUNTS
 		.iter()
-		.map(|(address, balance)| {
-			let address = *address;
-			let balance: types::BalanceOf<T> = T::BalanceTypeConversion::convert(*balance);
-			(address, balance)
-		})
+		.map(|(address, balance)| Ok((*address, types::try_to_balance::<T>(*balance)?)))
 		.collect()
 }
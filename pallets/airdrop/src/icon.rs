@@ -0,0 +1,262 @@
+//! Claims whose snapshot is taken from ICON. Source address sign an
+//! `icx_sendTransaction` message whose wallet param is the ice destination
+
+use crate as airdrop;
+use airdrop::{types, utils};
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{traits::ConstU32, traits::Get, BoundedVec, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+use types::{SignatureValidationError, SourceChainVerifier};
+
+/// Maximum length of signed `icx_sendTransaction` message
+pub const MAX_PAYLOAD_LENGTH: u32 = 1024;
+
+/// Method every signed transaction starts with
+const SEND_TRANSACTION_PREFIX: &[u8] = b"icx_sendTransaction.";
+
+/// Maximum nesting of `{}` and `[]` accepted by parser
+const MAX_DEPTH: u8 = 4;
+
+/// Serialized `icx_sendTransaction` as signed by icon address
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct IconPayload(BoundedVec<u8, ConstU32<MAX_PAYLOAD_LENGTH>>);
+
+impl AsRef<[u8]> for IconPayload {
+	fn as_ref(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+impl TryFrom<Vec<u8>> for IconPayload {
+	type Error = ();
+
+	fn try_from(payload: Vec<u8>) -> Result<Self, Self::Error> {
+		payload.try_into().map(Self).map_err(|_| ())
+	}
+}
+
+/// Fields of signed transaction that are relevant to a claim
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct IconTransaction {
+	pub from: types::IconAddress,
+	pub to: Vec<u8>,
+	pub nid: u64,
+	pub timestamp: u64,
+	/// Destination of claim as written in `data.params.wallet`
+	pub wallet: Vec<u8>,
+}
+
+/// Value of a key in ICON transaction serialization
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+enum Value {
+	Text(Vec<u8>),
+	Map(Vec<(Vec<u8>, Value)>),
+	List(Vec<Value>),
+}
+
+/// Parser of ICON transaction serialization used for tx hash i.e
+/// `key.value` pairs sorted by key and separated by `.`, with nested
+/// object inside `{}`, array inside `[]` and special chars escaped by `\`
+struct Parser<'a> {
+	input: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn peek(&self) -> Option<u8> {
+		self.input.get(self.pos).copied()
+	}
+
+	fn expect(&mut self, byte: u8) -> Option<()> {
+		if self.peek()? != byte {
+			return None;
+		}
+		self.pos += 1;
+		Some(())
+	}
+
+	fn is_finished(&self) -> bool {
+		self.pos == self.input.len()
+	}
+
+	fn parse_text(&mut self) -> Option<Vec<u8>> {
+		let mut text = Vec::new();
+		while let Some(byte) = self.peek() {
+			match byte {
+				b'.' | b'{' | b'}' | b'[' | b']' => break,
+				b'\\' => {
+					self.pos += 1;
+					text.push(self.peek()?);
+				}
+				_ => text.push(byte),
+			}
+			self.pos += 1;
+		}
+		Some(text)
+	}
+
+	fn parse_value(&mut self, depth: u8) -> Option<Value> {
+		match self.peek() {
+			Some(b'{') => {
+				self.pos += 1;
+				let map = self.parse_map(depth.checked_sub(1)?)?;
+				self.expect(b'}')?;
+				Some(Value::Map(map))
+			}
+			Some(b'[') => {
+				self.pos += 1;
+				let list = self.parse_list(depth.checked_sub(1)?)?;
+				self.expect(b']')?;
+				Some(Value::List(list))
+			}
+			_ => self.parse_text().map(Value::Text),
+		}
+	}
+
+	fn parse_map(&mut self, depth: u8) -> Option<Vec<(Vec<u8>, Value)>> {
+		let mut map = Vec::new();
+		if self.peek() == Some(b'}') {
+			return Some(map);
+		}
+
+		loop {
+			let key = self.parse_text()?;
+			self.expect(b'.')?;
+			let value = self.parse_value(depth)?;
+			map.push((key, value));
+
+			if self.peek() != Some(b'.') {
+				return Some(map);
+			}
+			self.pos += 1;
+		}
+	}
+
+	fn parse_list(&mut self, depth: u8) -> Option<Vec<Value>> {
+		let mut list = Vec::new();
+		if self.peek() == Some(b']') {
+			return Some(list);
+		}
+
+		loop {
+			list.push(self.parse_value(depth)?);
+
+			if self.peek() != Some(b'.') {
+				return Some(list);
+			}
+			self.pos += 1;
+		}
+	}
+}
+
+fn get_field<'v>(map: &'v [(Vec<u8>, Value)], key: &[u8]) -> Option<&'v Value> {
+	map.iter()
+		.find(|(field, _)| field.as_slice() == key)
+		.map(|(_, value)| value)
+}
+
+fn get_text<'v>(map: &'v [(Vec<u8>, Value)], key: &[u8]) -> Option<&'v [u8]> {
+	match get_field(map, key)? {
+		Value::Text(text) => Some(text),
+		_ => None,
+	}
+}
+
+fn get_map<'v>(map: &'v [(Vec<u8>, Value)], key: &[u8]) -> Option<&'v [(Vec<u8>, Value)]> {
+	match get_field(map, key)? {
+		Value::Map(inner) => Some(inner),
+		_ => None,
+	}
+}
+
+/// Parse `0x` prefixed hex number
+fn parse_hex_number(text: &[u8]) -> Option<u64> {
+	let digits = core::str::from_utf8(text.strip_prefix(b"0x")?).ok()?;
+	u64::from_str_radix(digits, 16).ok()
+}
+
+/// Parse `hx` prefixed icon address of an account
+fn parse_icon_address(text: &[u8]) -> Option<types::IconAddress> {
+	let mut address = [0_u8; 20];
+	hex::decode_to_slice(text.strip_prefix(b"hx")?, &mut address).ok()?;
+	Some(address)
+}
+
+/// Parse signed `icx_sendTransaction` message.
+/// None if message is not well formed or is not a `transfer` call
+pub fn parse_transaction(payload: &[u8]) -> Option<IconTransaction> {
+	let mut parser = Parser {
+		input: payload.strip_prefix(SEND_TRANSACTION_PREFIX)?,
+		pos: 0,
+	};
+	let fields = parser.parse_map(MAX_DEPTH)?;
+	if !parser.is_finished() {
+		return None;
+	}
+
+	if get_text(&fields, b"dataType")? != b"call" {
+		return None;
+	}
+	let data = get_map(&fields, b"data")?;
+	if get_text(data, b"method")? != b"transfer" {
+		return None;
+	}
+	let params = get_map(data, b"params")?;
+
+	Some(IconTransaction {
+		from: parse_icon_address(get_text(&fields, b"from")?)?,
+		to: get_text(&fields, b"to")?.to_vec(),
+		nid: parse_hex_number(get_text(&fields, b"nid")?)?,
+		timestamp: parse_hex_number(get_text(&fields, b"timestamp")?)?,
+		wallet: get_text(params, b"wallet")?.to_vec(),
+	})
+}
+
+/// Verify claims signed on ICON network whose id is `NetworkId`
+pub struct IconVerifier<NetworkId>(PhantomData<NetworkId>);
+
+impl<NetworkId: Get<u64>> SourceChainVerifier for IconVerifier<NetworkId> {
+	type Payload = IconPayload;
+	type EvmPayload = IconPayload;
+
+	fn extract_destination(payload: &[u8], destination_len: usize) -> Option<Vec<u8>> {
+		let transaction = parse_transaction(payload)?;
+
+		// Destination is written as hex with optional 0x prefix
+		let wallet = transaction.wallet;
+		let wallet = wallet.strip_prefix(b"0x").unwrap_or(&wallet[..]);
+		let destination = hex::decode(wallet).ok()?;
+		if destination.len() != destination_len {
+			return None;
+		}
+
+		Some(destination)
+	}
+
+	fn signed_at(payload: &[u8]) -> Option<u64> {
+		// Icon transaction timestamp is in microseconds
+		parse_transaction(payload).map(|transaction| transaction.timestamp / 1_000)
+	}
+
+	fn recover_signer(
+		signature: &types::IconSignature,
+		payload: &[u8],
+	) -> Result<types::IconAddress, SignatureValidationError> {
+		let transaction =
+			parse_transaction(payload).ok_or(SignatureValidationError::MalformedPayload)?;
+		if transaction.nid != NetworkId::get() {
+			return Err(SignatureValidationError::NetworkMismatch);
+		}
+
+		let address: types::IconAddress = utils::recover_address(signature, payload)?
+			.try_into()
+			.map_err(|_| SignatureValidationError::InvalidIconAddress)?;
+		if address != transaction.from {
+			return Err(SignatureValidationError::SenderMismatch);
+		}
+
+		Ok(address)
+	}
+}
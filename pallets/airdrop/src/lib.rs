@@ -19,10 +19,18 @@ pub mod weights;
 
 pub mod merkle;
 
+/// ICON as source chain of airdrop
+pub mod icon;
+
 mod exchange_accounts;
 
 pub mod transfer;
 
+pub mod migrations;
+
+/// Offchain worker relaying claims queued through rpc
+pub mod offchain;
+
 pub use weights::*;
 
 #[cfg(not(test))]
@@ -33,18 +41,23 @@ pub(crate) use {eprintln as error, println as info};
 pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
-	use super::{exchange_accounts, transfer, types, utils, weights};
+	use super::{exchange_accounts, migrations, offchain, transfer, types, utils, weights};
 	use hex_literal::hex;
-	use sp_runtime::traits::Convert;
+	use sp_runtime::traits::{Convert, Saturating, UniqueSaturatedInto, Zero};
 
 	use frame_support::pallet_prelude::*;
 	use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 	use sp_std::prelude::*;
 
 	use crate::merkle;
-	use crate::types::MerkelProofValidator;
+	use crate::types::{
+		MerkelProofValidator, MerkleLeafEncoder, MerkleNodeHasher, SourceChainVerifier,
+	};
 	use frame_support::storage::bounded_vec::BoundedVec;
-	use frame_support::traits::{Currency, LockableCurrency, ReservableCurrency};
+	use frame_support::traits::{
+		Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency, UnixTime,
+		WithdrawReasons,
+	};
 	use sp_runtime::traits::Verify;
 	use weights::WeightInfo;
 
@@ -75,23 +88,115 @@ pub mod pallet {
 
 		type MerkelProofValidator: MerkelProofValidator<Self>;
 
+		/// How snapshot entry is encoded into merkle leaf
+		type MerkleLeafEncoder: MerkleLeafEncoder;
+
+		/// Hash function of leaf and inner nodes of merkle tree
+		type MerkleNodeHasher: MerkleNodeHasher;
+
+		/// Message format and signature scheme of the chain
+		/// where airdrop snapshot is taken from
+		type SourceChainVerifier: SourceChainVerifier;
+
 		type MaxProofSize: Get<u32>;
 
-		const VESTING_TERMS: VestingTerms;
+		/// Maximum number of claims that can be sent in a single batch
+		type MaxClaimBatchSize: Get<u32>;
+
+		/// Priority given to unsigned claim submitted by claimer themself
+		#[pallet::constant]
+		type UnsignedClaimPriority: Get<TransactionPriority>;
+
+		/// Number of blocks unsigned claim is kept valid in transaction pool
+		#[pallet::constant]
+		type UnsignedClaimLongevity: Get<TransactionLongevity>;
+
+		/// Account that receives whatever is left in creditor account
+		/// once claim deadline of a campaign have passed. Usually treasury
+		type UnclaimedFundsBeneficiary: Get<types::AccountIdOf<Self>>;
+
+		/// Account creditors are refilled from within the allowance
+		/// approved by governance. Usually treasury
+		type CreditorTopUpSource: Get<types::AccountIdOf<Self>>;
+
+		/// Maximum number of campaigns that can have creditor funding set.
+		/// Bounds the refilling done in `on_initialize`
+		#[pallet::constant]
+		type MaxFundedCampaigns: Get<u32>;
+
+		/// Source of current time that age of signed messages is checked against
+		type UnixTime: UnixTime;
+
+		/// Milliseconds a signed message can be used to claim after the
+		/// time it was signed at, as written in the message itself.
+		/// Not applied to messages with zero timestamp
+		#[pallet::constant]
+		type SignedClaimValidity: Get<u64>;
+
+		/// How offchain worker submit claims queued through rpc.
+		/// Use `()` to disable offchain claim processing
+		type OffchainClaimSubmitter: offchain::ClaimSubmitter<Self>;
 	}
 
+	/// Current version of pallet storage. See `migrations` module
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// Maximum number of consumed payloads of swept campaign removed per block
+	pub const PAYLOADS_CLEARED_PER_BLOCK: u32 = 64;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// ClaimRequest have been ok for given icon address
-		ClaimSuccess(types::IconAddress),
+		/// Claim of given icon address have been completed
+		ClaimSuccess {
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			claimer: types::AccountIdOf<T>,
+			/// Amount transferred instantly by this claim
+			instant_amount: types::BalanceOf<T>,
+			/// Amount locked in vesting schedule by this claim
+			vested_amount: types::BalanceOf<T>,
+			vesting_schedule: Option<types::VestingInfoOf<T>>,
+			block_number: types::BlockNumberOf<T>,
+			/// Phase completed by previous claim and not repeated
+			skipped_phase: Option<types::ClaimPhase>,
+		},
+
+		/// Instant transfer of claim have been made but vesting failed.
+		/// Vesting can be completed with `complete_partial_claim`
+		PartialClaim {
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			claimer: types::AccountIdOf<T>,
+			instant_amount: types::BalanceOf<T>,
+			block_number: types::BlockNumberOf<T>,
+			skipped_phase: Option<types::ClaimPhase>,
+			error: DispatchError,
+		},
+
+		/// Vested transfer of claim have failed
+		VestingFailed {
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			claimer: types::AccountIdOf<T>,
+			vesting_schedule: types::VestingInfoOf<T>,
+			error: DispatchError,
+		},
 
-		/// PartialClaimRequest have been ok for given icon address
-		ClaimPartialSuccess(types::IconAddress),
+		/// Claim request inside a batch was rejected for given icon address
+		ClaimFailed {
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			error: DispatchError,
+		},
+
+		/// All claims inside a batch have been processed
+		ClaimBatchProcessed { succeeded: u32, failed: u32 },
 
 		/// Value of ServerAccount storage have been changed
 		// Return old value and new one
@@ -106,14 +211,89 @@ pub mod pallet {
 			new_state: types::AirdropState,
 		},
 
-		/// New merkle root have been set
+		/// New merkle root have been set for given campaign
 		MerkleRootUpdated {
-			old_root: Option<[u8; 32]>,
+			campaign_id: types::CampaignId,
+			old_root: [u8; 32],
 			new_root: [u8; 32],
 		},
 
-		/// Creditor balance is running low
-		CreditorBalanceLow,
+		/// New airdrop campaign have been created
+		CampaignCreated {
+			campaign_id: types::CampaignId,
+			merkle_root: [u8; 32],
+		},
+
+		/// Campaign have been opened or closed
+		CampaignStateUpdated {
+			campaign_id: types::CampaignId,
+			old_state: types::CampaignState,
+			new_state: types::CampaignState,
+		},
+
+		/// Vesting terms of campaign have been updated
+		VestingTermsUpdated {
+			campaign_id: types::CampaignId,
+			old_terms: types::VestingTerms,
+			new_terms: types::VestingTerms,
+		},
+
+		/// Claim deadline of campaign have been changed
+		ClaimDeadlineUpdated {
+			campaign_id: types::CampaignId,
+			old_deadline: Option<types::BlockNumberOf<T>>,
+			new_deadline: Option<types::BlockNumberOf<T>>,
+		},
+
+		/// Unclaimed fund of campaign have been moved from creditor to beneficiary
+		UnclaimedFundsSwept {
+			campaign_id: types::CampaignId,
+			beneficiary: types::AccountIdOf<T>,
+			distributed: types::BalanceOf<T>,
+			swept: types::BalanceOf<T>,
+		},
+
+		/// New icon address have been allowed to make exchange claim
+		ExchangeAccountAdded {
+			icon_address: types::IconAddress,
+			amount: types::BalanceOf<T>,
+		},
+
+		/// Amount of exchange account have been changed
+		ExchangeAccountUpdated {
+			icon_address: types::IconAddress,
+			old_amount: types::BalanceOf<T>,
+			new_amount: types::BalanceOf<T>,
+		},
+
+		/// Exchange account have been removed before it was settled
+		ExchangeAccountRemoved {
+			icon_address: types::IconAddress,
+			amount: types::BalanceOf<T>,
+		},
+
+		/// Creditor balance of campaign have fallen below it's low water mark
+		CreditorBalanceLow {
+			campaign_id: types::CampaignId,
+			balance: types::BalanceOf<T>,
+			low_water_mark: types::BalanceOf<T>,
+		},
+
+		/// Low water mark and allowance of campaign creditor have been changed
+		CreditorFundingUpdated {
+			campaign_id: types::CampaignId,
+			funding: Option<types::CreditorFundingInfo<T>>,
+		},
+
+		/// Creditor of campaign have been refilled from top-up source
+		CreditorToppedUp {
+			campaign_id: types::CampaignId,
+			amount: types::BalanceOf<T>,
+			remaining_allowance: types::BalanceOf<T>,
+		},
+
+		/// Icon address have been revoked and can not claim anymore
+		IconAddressRevoked { icon_address: types::IconAddress },
 	}
 
 	#[pallet::storage]
@@ -122,8 +302,15 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_icon_snapshot_map)]
-	pub(super) type IconSnapshotMap<T: Config> =
-		StorageMap<_, Blake2_128, types::IconAddress, types::SnapshotInfo<T>, OptionQuery>;
+	pub(super) type IconSnapshotMap<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		types::CampaignId,
+		Blake2_128Concat,
+		types::IconAddress,
+		types::SnapshotInfo<T>,
+		OptionQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_ice_to_icon_map)]
@@ -139,24 +326,84 @@ pub mod pallet {
 	pub type ExchangeAccountsMap<T: Config> =
 		StorageMap<_, Twox64Concat, types::IconAddress, types::BalanceOf<T>, OptionQuery>;
 
+	/// Exchange accounts whose claim have been completed
+	/// mapped to the block in which it was completed
 	#[pallet::storage]
-	#[pallet::getter(fn try_get_merkle_root)]
-	pub type MerkleRoot<T: Config> = StorageValue<_, [u8; 32], OptionQuery>;
+	#[pallet::getter(fn get_exchange_settlement)]
+	pub type SettledExchangeAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, types::IconAddress, types::BlockNumberOf<T>, OptionQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn try_get_creditor_account)]
-	pub(super) type CreditorAccount<T: Config> =
-		StorageValue<_, types::AccountIdOf<T>, OptionQuery>;
+	#[pallet::getter(fn try_get_campaign)]
+	pub type Campaigns<T: Config> =
+		StorageMap<_, Twox64Concat, types::CampaignId, types::CampaignInfo<T>, OptionQuery>;
 
-	#[pallet::type_value]
-	pub(super) fn DefaultStorageVersion<T: Config>() -> u32 {
-		1_u32.into()
-	}
+	/// Total amount transferred out of creditor account by claims of a campaign
+	#[pallet::storage]
+	#[pallet::getter(fn get_distributed_amount)]
+	pub(super) type DistributedAmount<T: Config> =
+		StorageMap<_, Twox64Concat, types::CampaignId, types::BalanceOf<T>, ValueQuery>;
+
+	/// Low water mark and refill allowance of campaign creditor
+	#[pallet::storage]
+	#[pallet::getter(fn get_creditor_funding)]
+	pub type CreditorFunding<T: Config> =
+		StorageMap<_, Twox64Concat, types::CampaignId, types::CreditorFundingInfo<T>, OptionQuery>;
+
+	/// Campaigns that have creditor funding set, in the order
+	/// their creditors are checked for refill
+	#[pallet::storage]
+	#[pallet::getter(fn get_funded_campaigns)]
+	pub type FundedCampaigns<T: Config> =
+		StorageValue<_, BoundedVec<types::CampaignId, T::MaxFundedCampaigns>, ValueQuery>;
+
+	/// Whether creditor of campaign is below it's low water mark and
+	/// alert have already been made for this crossing
+	#[pallet::storage]
+	#[pallet::getter(fn is_creditor_low)]
+	pub(super) type CreditorBalanceIsLow<T: Config> =
+		StorageMap<_, Twox64Concat, types::CampaignId, bool, ValueQuery>;
+
+	/// Campaign each creditor account is serving. An account can be creditor
+	/// of single campaign at a time so that claims and sweep of one campaign
+	/// never touch funds meant for another
+	#[pallet::storage]
+	#[pallet::getter(fn get_creditor_campaign)]
+	pub type CreditorCampaign<T: Config> =
+		StorageMap<_, Blake2_128Concat, types::AccountIdOf<T>, types::CampaignId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_campaign_id)]
+	pub(super) type NextCampaignId<T: Config> = StorageValue<_, types::CampaignId, ValueQuery>;
+
+	/// Hash of signed payloads that have already been used to claim
+	/// in a campaign mapped to the block in which they were consumed.
+	/// Entries of a campaign are removed over following blocks once it
+	/// is swept, as swept campaign never accept claims again
+	#[pallet::storage]
+	#[pallet::getter(fn get_payload_consumption)]
+	pub type ConsumedPayloads<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		types::CampaignId,
+		Identity,
+		types::PayloadHash,
+		types::BlockNumberOf<T>,
+		OptionQuery,
+	>;
+
+	/// Swept campaigns whose consumed payloads are yet to be removed
+	#[pallet::storage]
+	#[pallet::getter(fn get_payloads_to_clear)]
+	pub type PayloadsToClear<T: Config> =
+		StorageMap<_, Twox64Concat, types::CampaignId, (), OptionQuery>;
 
+	/// Icon addresses that are not allowed to claim anymore
+	/// mapped to the block in which they were revoked
 	#[pallet::storage]
-	#[pallet::getter(fn get_storage_version)]
-	pub(super) type StorageVersion<T: Config> =
-		StorageValue<Value = u32, QueryKind = ValueQuery, OnEmpty = DefaultStorageVersion<T>>;
+	#[pallet::getter(fn get_revocation)]
+	pub type RevokedIconAddresses<T: Config> =
+		StorageMap<_, Blake2_128Concat, types::IconAddress, types::BlockNumberOf<T>, OptionQuery>;
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -206,12 +453,6 @@ pub mod pallet {
 		/// Unexpected format of AccountId
 		IncompatibleAccountId,
 
-		/// Merkle root is not set on chain yet
-		NoMerkleRoot,
-
-		/// Creditor account is not set on chain yet
-		NoCreditorAccount,
-
 		/// Provided ice address is not in expected format
 		InvalidIceAddress,
 
@@ -229,28 +470,119 @@ pub mod pallet {
 
 		/// Claim amount was not expected in this exchanged airdrop
 		InvalidClaimAmount,
+
+		/// No campaign exists with given id
+		NoSuchCampaign,
+
+		/// Campaign have been closed and do not accept claim anymore
+		CampaignClosed,
+
+		/// Campaign have not reached it's starting block yet
+		CampaignNotStarted,
+
+		/// Campaign have already passed it's ending block
+		CampaignEnded,
+
+		/// Legacy snapshots are still being moved into this campaign
+		SnapshotMigrationOngoing,
+
+		/// Ending block of campaign is before it's starting block
+		InvalidCampaignPeriod,
+
+		/// Instant percentage in vesting terms exceed 100
+		InvalidVestingTerms,
+
+		/// Campaign do not have a claim deadline or it have not passed yet
+		ClaimDeadlineNotReached,
+
+		/// Unclaimed fund of this campaign have already been swept
+		CampaignAlreadySwept,
+
+		/// Account is already creditor of another campaign that is not swept yet
+		CreditorInUse,
+
+		/// Part of creditor balance that would be swept is locked
+		CreditorFundsLocked,
+
+		/// Creditor funding is already set for `MaxFundedCampaigns` campaigns
+		TooManyFundedCampaigns,
+
+		/// This icon address is already registered as exchange account
+		ExchangeAccountExists,
+
+		/// This icon address is not registered as exchange account
+		NoSuchExchangeAccount,
+
+		/// Claim of this exchange account have already been settled
+		ExchangeClaimSettled,
+
+		/// Evm signature is invalid or not made by given evm address
+		InvalidEvmSignature,
+
+		/// Snapshot of given icon address is not partially claimed
+		NotPartialClaim,
+
+		/// Signed message is meant for different network of source chain
+		InvalidNetworkId,
+
+		/// Claim was not made before it's expiry block or signed
+		/// message with timestamp is older than `SignedClaimValidity`
+		ClaimExpired,
+
+		/// Same signed message have already been used to claim in this campaign
+		PayloadAlreadyUsed,
+
+		/// This icon address have been revoked and can not claim
+		IconAddressRevoked,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Refill creditors that have fallen below their low water mark.
+		/// At most `MaxFundedCampaigns` creditors are checked
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let migration_weight =
+				migrations::v2::migrate_snapshots::<T>(migrations::v2::SNAPSHOTS_PER_BLOCK)
+					.saturating_add(Self::clear_consumed_payloads());
+
+			<FundedCampaigns<T>>::get()
+				.into_iter()
+				.fold(T::DbWeight::get().reads(1), |weight, campaign_id| {
+					weight.saturating_add(Self::refill_creditor(campaign_id))
+				})
+				.saturating_add(migration_weight)
+		}
+
+		/// Relay claims queued in offchain storage
+		fn offchain_worker(_n: BlockNumberFor<T>) {
+			offchain::process_claim_queue::<T>();
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Dispatchable to be called by server with privileged account
-		/// dispatch claim
+		/// dispatch claim. `expiry` is advisory only as it is not signed
+		/// by claimer, see `ensure_not_replayed`
 		#[pallet::call_index(0)]
 		#[pallet::weight((
-			T::AirdropWeightInfo::dispatch_user_claim(),
+			T::AirdropWeightInfo::dispatch_user_claim()
+				.saturating_add(Pallet::<T>::ice_signature_extra_weight(ice_signature)),
 			DispatchClass::Normal,
 			Pays::Yes
 		))]
 		pub fn dispatch_user_claim(
 			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
 			icon_address: types::IconAddress,
 			ice_address: types::IceAddress,
-			message: types::RawPayload,
+			message: types::RawPayload<T>,
 			icon_signature: types::IconSignature,
 			ice_signature: types::IceSignature,
 			total_amount: types::BalanceOf<T>,
 			defi_user: bool,
 			proofs: types::MerkleProofs<T>,
+			expiry: types::BlockNumberOf<T>,
 		) -> DispatchResultWithPostInfo {
 			// Make sure only root or server account call call this
 			Self::ensure_root_or_server(origin).map_err(|_| Error::<T>::DeniedOperation)?;
@@ -258,70 +590,19 @@ pub mod pallet {
 			// Make sure node is accepting new claim-request
 			Self::ensure_user_claim_switch()?;
 
-			// Verify the integrity of message
-			Self::validate_message_payload(&message, &ice_address).map_err(|e| {
-				super::info!(
-					"claim request by: {icon_address:?}. Rejected at: validate_message_payload(). Error: {e:?}"
-				);
-				e
-			})?;
-
-			// We expect a valid proof of this exchange call
-			Self::validate_merkle_proof(&icon_address, total_amount, defi_user, proofs).map_err(
-				|e| {
-					super::info!(
-						"claim request by: {icon_address:?}. Rejected at: validate_merkle_proof()"
-					);
-					e
-				},
-			)?;
-
-			// Validate icon signature
-			Self::validate_icon_address(&icon_address, &icon_signature, &message).map_err(|e| {
-				super::info!(
-					"claim request by: {icon_address:?}. Rejected at:  validate_icon_address()"
-				);
-				e
-			})?;
-
-			// Validate ice signature
-			Self::validate_ice_signature(&ice_signature, &icon_signature, &ice_address).map_err(
-				|e| {
-					super::info!(
-						"claim request by: {icon_address:?}. Rejected at: validate_ice_signature()"
-					);
-					e
-				},
+			Self::do_user_claim(
+				campaign_id,
+				icon_address,
+				ice_address,
+				message,
+				icon_signature,
+				ice_signature,
+				total_amount,
+				defi_user,
+				proofs,
+				expiry,
 			)?;
 
-			// Now this address pair is verified,
-			// we can insert it to the map if this pair is new
-			let mut snapshot =
-				Self::insert_or_get_snapshot(&icon_address, &ice_address, defi_user, total_amount)
-					.map_err(|e| {
-						super::info!("claim request by: {icon_address:?}. Rejected at: insert_or_get_snapshot. error: {e:?}");
-						e
-					})?;
-
-			// Make sure this user is eligible for claim.
-			Self::ensure_claimable(&snapshot).map_err(|e| {
-				super::info!("claim request by: {icon_address:?}. Rejected at: ensure_claimable(). Snapshot: {snapshot:?}.");
-				e
-			})?;
-
-			// We also make sure creditor have enough fund to complete this airdrop
-			Self::validate_creditor_fund(total_amount).map_err(|e| {
-				super::error!("claim request by: {icon_address:?}. Rejected at: validate_creditor_fund(). Amount: {total_amount:?}");
-				e
-			})?;
-
-			// Do the actual transfer if eligible
-			Self::do_transfer(&mut snapshot, &icon_address).map_err(|e| {
-				super::error!("claim request by: {icon_address:?}. Failed at: do_transfer(). Reason: {e:?}. Snapshot: {snapshot:?}");
-				e
-			})?;
-
-			Self::deposit_event(Event::ClaimSuccess(icon_address));
 			Ok(Pays::No.into())
 		}
 
@@ -333,6 +614,7 @@ pub mod pallet {
 		))]
 		pub fn dispatch_exchange_claim(
 			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
 			icon_address: types::IconAddress,
 			ice_address: types::IceAddress,
 			total_amount: types::BalanceOf<T>,
@@ -341,42 +623,63 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
 			Self::ensure_exchange_claim_switch()?;
+			Self::ensure_campaign_active(campaign_id)?;
+			Self::ensure_not_revoked(&icon_address)?;
 
+			ensure!(
+				!<SettledExchangeAccounts<T>>::contains_key(icon_address),
+				Error::<T>::ExchangeClaimSettled
+			);
 			let amount = Self::validate_whitelisted(&icon_address)?;
 			ensure!(total_amount == amount, Error::<T>::InvalidClaimAmount);
 
-			Self::validate_merkle_proof(&icon_address, total_amount, defi_user, proofs).map_err(
-				|e| {
-					super::info!(
+			Self::validate_merkle_proof(
+				campaign_id,
+				&icon_address,
+				total_amount,
+				defi_user,
+				proofs,
+			)
+			.map_err(|e| {
+				super::info!(
 						"Exchange for: {icon_address:?}. Failed at: validate_merkle_proof(). Reason: {e:?}"
 					);
-					e
-				},
-			)?;
-			Self::validate_creditor_fund(total_amount).map_err(|e| {
+				e
+			})?;
+			Self::validate_creditor_fund(campaign_id, total_amount).map_err(|e| {
 				super::error!("Exchange for: {icon_address:?}. Failed at: validate_creditor_fund. Amount: {total_amount:?}");
 				e
 			})?;
 
-			let mut snapshot =
-				Self::insert_or_get_snapshot(&icon_address, &ice_address, defi_user, total_amount)
-					.map_err(|e| {
-						super::error!(
-							"Exchange for: {icon_address:?}. Failed at: insert_or_get_snapshot."
-						);
-						e
-					})?;
+			let mut snapshot = Self::insert_or_get_snapshot(
+				campaign_id,
+				&icon_address,
+				&ice_address,
+				defi_user,
+				total_amount,
+			)
+			.map_err(|e| {
+				super::error!("Exchange for: {icon_address:?}. Failed at: insert_or_get_snapshot.");
+				e
+			})?;
 
 			Self::ensure_claimable(&snapshot).map_err(|e| {
 				super::info!("Exchange for: {icon_address:?}. Failed at: ensure_claimable. Snapshot: {snapshot:?}");
 				e
 			})?;
-			Self::do_transfer(&mut snapshot, &icon_address).map_err(|e| {
+			Self::do_transfer(campaign_id, &mut snapshot, &icon_address).map_err(|e| {
 				super::error!("Exchange for: {icon_address:?}. Failed at: do_transfer. Snapshot: {snapshot:?}. Reason: {e:?}");
 				e
 			})?;
 
-			Self::deposit_event(Event::ClaimSuccess(icon_address));
+			// Exchange account is settled only once
+			// no matter which campaign it was claimed in
+			<ExchangeAccountsMap<T>>::remove(icon_address);
+			<SettledExchangeAccounts<T>>::insert(
+				icon_address,
+				utils::get_current_block_number::<T>(),
+			);
+
 			Ok(Pays::No.into())
 		}
 
@@ -406,18 +709,30 @@ pub mod pallet {
 
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::AirdropWeightInfo::change_merkle_root())]
-		pub fn change_merkle_root(origin: OriginFor<T>, new_root: [u8; 32]) -> DispatchResult {
+		pub fn change_merkle_root(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			new_root: [u8; 32],
+		) -> DispatchResult {
 			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
-			let old_root = Self::try_get_merkle_root();
 
-			MerkleRoot::<T>::put(&new_root);
+			let old_root = <Campaigns<T>>::try_mutate(campaign_id, |campaign| {
+				let campaign = campaign.as_mut().ok_or(Error::<T>::NoSuchCampaign)?;
+				let old_root = campaign.merkle_root;
+				campaign.merkle_root = new_root;
+				Ok::<_, Error<T>>(old_root)
+			})?;
 
 			super::info!(
-				"Merkle root changed from {old_root:?} to {new_root:?} at height {bl_num:?}",
+				"Merkle root of campaign {campaign_id} changed from {old_root:?} to {new_root:?} at height {bl_num:?}",
 				bl_num = utils::get_current_block_number::<T>()
 			);
 
-			Self::deposit_event(Event::<T>::MerkleRootUpdated { old_root, new_root });
+			Self::deposit_event(Event::<T>::MerkleRootUpdated {
+				campaign_id,
+				old_root,
+				new_root,
+			});
 			Ok(())
 		}
 
@@ -445,61 +760,1130 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
-	}
 
-	// implement all the helper function that are called from pallet dispatchable
-	impl<T: Config> Pallet<T> {
-		/// Check weather node is set to block incoming claim request
-		/// Return error in that case else return Ok
-		pub fn ensure_user_claim_switch() -> DispatchResult {
-			let is_disabled = Self::get_airdrop_state().block_claim_request;
+		/// Dispatchable to be called by server with privileged account
+		/// dispatch several claims at once. Failure of one claim do not
+		/// revert the others, result of each claim is reported as event
+		#[pallet::call_index(5)]
+		#[pallet::weight((
+			T::AirdropWeightInfo::dispatch_user_claims_batch(claims.len() as u32).saturating_add(
+				claims.iter().fold(Weight::zero(), |total, claim| {
+					let extra = Pallet::<T>::ice_signature_extra_weight(&claim.ice_signature);
+					total.saturating_add(extra)
+				})
+			),
+			DispatchClass::Normal,
+			Pays::Yes
+		))]
+		pub fn dispatch_user_claims_batch(
+			origin: OriginFor<T>,
+			claims: types::UserClaimBatch<T>,
+		) -> DispatchResultWithPostInfo {
+			// Make sure only root or server account call call this
+			Self::ensure_root_or_server(origin).map_err(|_| Error::<T>::DeniedOperation)?;
 
-			if is_disabled {
-				Err(Error::<T>::NewClaimRequestBlocked.into())
-			} else {
-				Ok(())
+			// Make sure node is accepting new claim-request
+			Self::ensure_user_claim_switch()?;
+
+			let mut succeeded = 0_u32;
+			let mut failed = 0_u32;
+			for claim in claims {
+				let campaign_id = claim.campaign_id;
+				let icon_address = claim.icon_address;
+
+				// Run each claim in it's own storage layer so that
+				// failed claim do not leave any partial change behind
+				let claim_res = frame_support::storage::with_storage_layer(|| {
+					Self::do_user_claim(
+						claim.campaign_id,
+						claim.icon_address,
+						claim.ice_address,
+						claim.message,
+						claim.icon_signature,
+						claim.ice_signature,
+						claim.amount,
+						claim.defi_user,
+						claim.proofs,
+						claim.expiry,
+					)
+				});
+
+				match claim_res {
+					Ok(()) => succeeded += 1,
+					Err(error) => {
+						failed += 1;
+						Self::deposit_event(Event::ClaimFailed {
+							campaign_id,
+							icon_address,
+							error,
+						});
+					}
+				}
 			}
-		}
 
-		pub fn get_creditor_account() -> Result<types::AccountIdOf<T>, Error<T>> {
-			Self::try_get_creditor_account().ok_or(Error::<T>::NoCreditorAccount)
+			Self::deposit_event(Event::ClaimBatchProcessed { succeeded, failed });
+			Ok(Pays::No.into())
 		}
 
-		pub fn get_merkle_root() -> Result<[u8; 32], Error<T>> {
-			Self::try_get_merkle_root().ok_or(Error::<T>::NoMerkleRoot)
+		/// Dispatchable to be submitted as unsigned transaction by claimer
+		/// so that claim do not depend on server account.
+		/// All the validation is done before including in block
+		/// in `ValidateUnsigned` implementation
+		#[pallet::call_index(6)]
+		#[pallet::weight((
+			T::AirdropWeightInfo::dispatch_user_claim()
+				.saturating_add(Pallet::<T>::ice_signature_extra_weight(ice_signature)),
+			DispatchClass::Normal,
+			Pays::No
+		))]
+		pub fn claim_unsigned(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			ice_address: types::IceAddress,
+			message: types::RawPayload<T>,
+			icon_signature: types::IconSignature,
+			ice_signature: types::IceSignature,
+			total_amount: types::BalanceOf<T>,
+			defi_user: bool,
+			proofs: types::MerkleProofs<T>,
+			expiry: types::BlockNumberOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			// Make sure node is accepting new claim-request
+			Self::ensure_user_claim_switch()?;
+
+			Self::do_user_claim(
+				campaign_id,
+				icon_address,
+				ice_address,
+				message,
+				icon_signature,
+				ice_signature,
+				total_amount,
+				defi_user,
+				proofs,
+				expiry,
+			)?;
+
+			Ok(Pays::No.into())
 		}
 
-		/// Check weather node is set to block incoming exchange request
-		/// Return error in that case else return Ok
-		pub fn ensure_exchange_claim_switch() -> DispatchResult {
-			let is_disabled = Self::get_airdrop_state().block_exchange_request;
+		/// Start a new airdrop round with it's own merkle tree,
+		/// vesting terms and creditor account.
+		/// Creditor must not be backing any other campaign that is not swept yet
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::create_campaign())]
+		pub fn create_campaign(
+			origin: OriginFor<T>,
+			merkle_root: [u8; 32],
+			creditor: types::AccountIdOf<T>,
+			vesting_terms: types::VestingTerms,
+			start_block: types::BlockNumberOf<T>,
+			end_block: Option<types::BlockNumberOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
 
-			if is_disabled {
-				Err(Error::<T>::NewExchangeRequestBlocked.into())
-			} else {
-				Ok(())
+			ensure!(vesting_terms.is_valid(), Error::<T>::InvalidVestingTerms);
+			if let Some(end_block) = end_block {
+				ensure!(end_block >= start_block, Error::<T>::InvalidCampaignPeriod);
 			}
-		}
+			ensure!(
+				!<CreditorCampaign<T>>::contains_key(&creditor),
+				Error::<T>::CreditorInUse
+			);
 
-		/// Helper function to create similar interface like `ensure_root`
-		/// but which instead check for server key
-		pub fn ensure_root_or_server(origin: OriginFor<T>) -> DispatchResult {
-			let is_root = ensure_root(origin.clone()).is_ok();
-			let is_offchain = {
-				let signed = ensure_signed(origin);
-				signed.is_ok() && signed.ok() == Self::get_airdrop_server_account()
+			let campaign_id = Self::get_next_campaign_id();
+			let next_campaign_id = campaign_id
+				.checked_add(1)
+				.ok_or(Error::<T>::ArithmeticError)?;
+
+			let campaign = types::CampaignInfo::<T> {
+				merkle_root,
+				vesting_terms,
+				creditor: creditor.clone(),
+				state: types::CampaignState::Open,
+				start_block,
+				end_block,
 			};
+			<Campaigns<T>>::insert(campaign_id, campaign);
+			<CreditorCampaign<T>>::insert(creditor, campaign_id);
+			<NextCampaignId<T>>::put(next_campaign_id);
 
-			ensure!(is_root || is_offchain, DispatchError::BadOrigin);
+			super::info!(
+				"Campaign {campaign_id} created with root {merkle_root:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::CampaignCreated {
+				campaign_id,
+				merkle_root,
+			});
 			Ok(())
 		}
 
-		// Insert this address pair if it is new
-		pub fn insert_or_get_snapshot(
-			icon_address: &types::IconAddress,
-			ice_address: &types::IceAddress,
-			defi_user: bool,
-			amount: types::BalanceOf<T>,
+		/// Open or close given campaign.
+		/// Closed campaign do not accept any claim
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::update_campaign_state())]
+		pub fn update_campaign_state(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			new_state: types::CampaignState,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+			// Swept state can only be reached through sweep_unclaimed_funds
+			ensure!(
+				new_state != types::CampaignState::Swept,
+				Error::<T>::DeniedOperation
+			);
+
+			let old_state = <Campaigns<T>>::try_mutate(campaign_id, |campaign| {
+				let campaign = campaign.as_mut().ok_or(Error::<T>::NoSuchCampaign)?;
+				let old_state = campaign.state;
+				ensure!(
+					old_state != types::CampaignState::Swept,
+					Error::<T>::CampaignAlreadySwept
+				);
+				campaign.state = new_state;
+				Ok::<_, Error<T>>(old_state)
+			})?;
+
+			super::info!(
+				"State of campaign {campaign_id} changed from {old_state:?} to {new_state:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::CampaignStateUpdated {
+				campaign_id,
+				old_state,
+				new_state,
+			});
+			Ok(())
+		}
+
+		/// Update the instant/vesting split of given campaign.
+		/// Only claims made after this call are affected
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::set_vesting_terms())]
+		pub fn set_vesting_terms(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			new_terms: types::VestingTerms,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+			ensure!(new_terms.is_valid(), Error::<T>::InvalidVestingTerms);
+
+			let old_terms = <Campaigns<T>>::try_mutate(campaign_id, |campaign| {
+				let campaign = campaign.as_mut().ok_or(Error::<T>::NoSuchCampaign)?;
+				let old_terms = campaign.vesting_terms;
+				campaign.vesting_terms = new_terms;
+				Ok::<_, Error<T>>(old_terms)
+			})?;
+
+			super::info!(
+				"Vesting terms of campaign {campaign_id} changed from {old_terms:?} to {new_terms:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::VestingTermsUpdated {
+				campaign_id,
+				old_terms,
+				new_terms,
+			});
+			Ok(())
+		}
+
+		/// Set the last block at which claims of given campaign are accepted.
+		/// None removes the deadline
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::set_claim_deadline())]
+		pub fn set_claim_deadline(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			new_deadline: Option<types::BlockNumberOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			let old_deadline = <Campaigns<T>>::try_mutate(campaign_id, |campaign| {
+				let campaign = campaign.as_mut().ok_or(Error::<T>::NoSuchCampaign)?;
+				ensure!(
+					campaign.state != types::CampaignState::Swept,
+					Error::<T>::CampaignAlreadySwept
+				);
+				if let Some(new_deadline) = new_deadline {
+					ensure!(
+						new_deadline >= campaign.start_block,
+						Error::<T>::InvalidCampaignPeriod
+					);
+				}
+
+				let old_deadline = campaign.end_block;
+				campaign.end_block = new_deadline;
+				Ok::<_, Error<T>>(old_deadline)
+			})?;
+
+			super::info!(
+				"Claim deadline of campaign {campaign_id} changed from {old_deadline:?} to {new_deadline:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::ClaimDeadlineUpdated {
+				campaign_id,
+				old_deadline,
+				new_deadline,
+			});
+			Ok(())
+		}
+
+		/// Once claim deadline of campaign have passed, move everything
+		/// left in it's creditor account to `UnclaimedFundsBeneficiary`.
+		/// Existential deposit is kept so creditor is never reaped.
+		/// Campaign is marked as swept and cannot be reopened afterwards
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::sweep_unclaimed_funds())]
+		pub fn sweep_unclaimed_funds(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			let mut campaign = Self::get_campaign(campaign_id)?;
+			ensure!(
+				campaign.state != types::CampaignState::Swept,
+				Error::<T>::CampaignAlreadySwept
+			);
+
+			let current_block = utils::get_current_block_number::<T>();
+			let deadline_passed = campaign
+				.end_block
+				.map_or(false, |end_block| current_block > end_block);
+			ensure!(deadline_passed, Error::<T>::ClaimDeadlineNotReached);
+
+			// Creditor serve only this campaign so everything above
+			// existential deposit is left over of it. Reserved balance is not
+			// part of free balance and is never touched
+			let beneficiary = T::UnclaimedFundsBeneficiary::get();
+			let free = <T as Config>::Currency::free_balance(&campaign.creditor);
+			let swept = free.saturating_sub(<T as Config>::Currency::minimum_balance());
+			<T as Config>::Currency::ensure_can_withdraw(
+				&campaign.creditor,
+				swept,
+				WithdrawReasons::TRANSFER,
+				free.saturating_sub(swept),
+			)
+			.map_err(|_| Error::<T>::CreditorFundsLocked)?;
+			<T as Config>::Currency::transfer(
+				&campaign.creditor,
+				&beneficiary,
+				swept,
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|e| {
+				super::error!("Sweep of campaign {campaign_id}. Failed at: transfer. Amount: {swept:?}. Reason: {e:?}");
+				e
+			})?;
+
+			// Creditor is free to serve another campaign from now on
+			// and is not to be refilled anymore
+			<CreditorCampaign<T>>::remove(&campaign.creditor);
+			<FundedCampaigns<T>>::mutate(|funded| funded.retain(|id| *id != campaign_id));
+			<CreditorFunding<T>>::remove(campaign_id);
+			<CreditorBalanceIsLow<T>>::remove(campaign_id);
+			<PayloadsToClear<T>>::insert(campaign_id, ());
+			campaign.state = types::CampaignState::Swept;
+			<Campaigns<T>>::insert(campaign_id, campaign);
+
+			let distributed = Self::get_distributed_amount(campaign_id);
+			super::info!(
+				"Campaign {campaign_id} swept {swept:?} to {beneficiary:?} after distributing {distributed:?} at height {current_block:?}"
+			);
+
+			Self::deposit_event(Event::<T>::UnclaimedFundsSwept {
+				campaign_id,
+				beneficiary,
+				distributed,
+				swept,
+			});
+			Ok(())
+		}
+
+		/// Allow given icon address to make exchange claim of given amount
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::add_exchange_account())]
+		pub fn add_exchange_account(
+			origin: OriginFor<T>,
+			icon_address: types::IconAddress,
+			amount: types::BalanceOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			ensure!(
+				!<SettledExchangeAccounts<T>>::contains_key(icon_address),
+				Error::<T>::ExchangeClaimSettled
+			);
+			ensure!(
+				!<ExchangeAccountsMap<T>>::contains_key(icon_address),
+				Error::<T>::ExchangeAccountExists
+			);
+			<ExchangeAccountsMap<T>>::insert(icon_address, amount);
+
+			super::info!(
+				"Exchange account {icon_address:?} added with amount {amount:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::ExchangeAccountAdded {
+				icon_address,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Change the amount given exchange account is allowed to claim
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::update_exchange_account())]
+		pub fn update_exchange_account(
+			origin: OriginFor<T>,
+			icon_address: types::IconAddress,
+			new_amount: types::BalanceOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			let old_amount = <ExchangeAccountsMap<T>>::try_mutate(icon_address, |amount| {
+				let amount = amount.as_mut().ok_or(Error::<T>::NoSuchExchangeAccount)?;
+				let old_amount = *amount;
+				*amount = new_amount;
+				Ok::<_, Error<T>>(old_amount)
+			})?;
+
+			super::info!(
+				"Exchange account {icon_address:?} amount changed from {old_amount:?} to {new_amount:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::ExchangeAccountUpdated {
+				icon_address,
+				old_amount,
+				new_amount,
+			});
+			Ok(())
+		}
+
+		/// Remove given exchange account so that it can no longer claim
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::remove_exchange_account())]
+		pub fn remove_exchange_account(
+			origin: OriginFor<T>,
+			icon_address: types::IconAddress,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			let amount = <ExchangeAccountsMap<T>>::take(icon_address)
+				.ok_or(Error::<T>::NoSuchExchangeAccount)?;
+
+			super::info!(
+				"Exchange account {icon_address:?} removed at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::ExchangeAccountRemoved {
+				icon_address,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Dispatchable to be called by server with privileged account
+		/// dispatch claim whose destination is an ice evm address.
+		/// Fund is credited to the account evm address is mapped into
+		#[pallet::call_index(15)]
+		#[pallet::weight((
+			T::AirdropWeightInfo::dispatch_evm_user_claim(),
+			DispatchClass::Normal,
+			Pays::Yes
+		))]
+		pub fn dispatch_evm_user_claim(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			evm_address: types::IceEvmAddress,
+			message: types::EvmRawPayload<T>,
+			icon_signature: types::IconSignature,
+			evm_signature: types::IceEvmSignature,
+			total_amount: types::BalanceOf<T>,
+			defi_user: bool,
+			proofs: types::MerkleProofs<T>,
+			expiry: types::BlockNumberOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// Make sure only root or server account call call this
+			Self::ensure_root_or_server(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			// Make sure node is accepting new claim-request
+			Self::ensure_user_claim_switch()?;
+
+			Self::do_evm_user_claim(
+				campaign_id,
+				icon_address,
+				evm_address,
+				message,
+				icon_signature,
+				evm_signature,
+				total_amount,
+				defi_user,
+				proofs,
+				expiry,
+			)?;
+
+			Ok(Pays::No.into())
+		}
+
+		/// Retry the vesting of a claim whose instant transfer have been
+		/// made but vesting failed. Can be called by root, server or
+		/// the claimer itself who pays the fee
+		#[pallet::call_index(16)]
+		#[pallet::weight((
+			T::AirdropWeightInfo::complete_partial_claim(),
+			DispatchClass::Normal,
+			Pays::Yes
+		))]
+		pub fn complete_partial_claim(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+		) -> DispatchResultWithPostInfo {
+			let is_privileged = Self::ensure_root_or_server(origin.clone()).is_ok();
+
+			let mut snapshot = Self::get_icon_snapshot_map(campaign_id, icon_address)
+				.ok_or(Error::<T>::NotPartialClaim)?;
+			if !is_privileged {
+				let signer = ensure_signed(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+				ensure!(signer == snapshot.ice_address, Error::<T>::DeniedOperation);
+			}
+			ensure!(snapshot.is_partial(), Error::<T>::NotPartialClaim);
+
+			Self::ensure_user_claim_switch()?;
+			Self::ensure_campaign_active(campaign_id)?;
+			Self::ensure_not_revoked(&icon_address)?;
+
+			let vesting_amount = snapshot.amount.saturating_sub(snapshot.initial_transfer);
+			Self::validate_creditor_fund(campaign_id, vesting_amount)?;
+
+			let outcome =
+				Self::do_transfer(campaign_id, &mut snapshot, &icon_address).map_err(|e| {
+					super::error!("partial claim of: {icon_address:?}. Failed at: do_transfer(). Reason: {e:?}");
+					e
+				})?;
+
+			// Only vesting was retried. Report back if it failed again
+			if let Some(error) = outcome.vesting_error {
+				return Err(error.into());
+			}
+
+			// Only root and server are exempt from fee
+			let pays = if is_privileged { Pays::No } else { Pays::Yes };
+			Ok(pays.into())
+		}
+
+		/// Set low water mark of campaign creditor and the allowance it can
+		/// be refilled from. Passing None remove both
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::set_creditor_funding())]
+		pub fn set_creditor_funding(
+			origin: OriginFor<T>,
+			campaign_id: types::CampaignId,
+			funding: Option<types::CreditorFundingInfo<T>>,
+		) -> DispatchResult {
+			ensure_root(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+
+			ensure!(
+				<Campaigns<T>>::contains_key(campaign_id),
+				Error::<T>::NoSuchCampaign
+			);
+			<FundedCampaigns<T>>::try_mutate(|funded| {
+				let position = funded.iter().position(|id| *id == campaign_id);
+				match (&funding, position) {
+					(Some(_), None) => funded
+						.try_push(campaign_id)
+						.map_err(|_| Error::<T>::TooManyFundedCampaigns),
+					(None, Some(index)) => {
+						funded.remove(index);
+						Ok(())
+					}
+					_ => Ok(()),
+				}
+			})?;
+			<CreditorFunding<T>>::set(campaign_id, funding.clone());
+
+			// Start tracking crossing against new mark afresh
+			<CreditorBalanceIsLow<T>>::remove(campaign_id);
+
+			super::info!(
+				"Creditor funding of campaign {campaign_id} changed to {funding:?} at height {bl_num:?}",
+				bl_num = utils::get_current_block_number::<T>()
+			);
+
+			Self::deposit_event(Event::<T>::CreditorFundingUpdated {
+				campaign_id,
+				funding,
+			});
+			Ok(())
+		}
+
+		/// Stop given icon address from making any further claim
+		/// in all campaigns. Claims already made are left untouched
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::AirdropWeightInfo::revoke_icon_address())]
+		pub fn revoke_icon_address(
+			origin: OriginFor<T>,
+			icon_address: types::IconAddress,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_root_or_server(origin).map_err(|_| Error::<T>::DeniedOperation)?;
+			Self::ensure_not_revoked(&icon_address)?;
+
+			let bl_num = utils::get_current_block_number::<T>();
+			<RevokedIconAddresses<T>>::insert(icon_address, bl_num);
+
+			super::info!("Icon address {icon_address:?} revoked at height {bl_num:?}");
+
+			Self::deposit_event(Event::<T>::IconAddressRevoked { icon_address });
+			Ok(Pays::No.into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::claim_unsigned {
+				campaign_id,
+				icon_address,
+				ice_address,
+				message,
+				icon_signature,
+				ice_signature,
+				total_amount,
+				defi_user,
+				proofs,
+				expiry,
+			} = call
+			{
+				Self::validate_unsigned_claim(
+					*campaign_id,
+					icon_address,
+					ice_address,
+					message,
+					icon_signature,
+					ice_signature,
+					*total_amount,
+					*defi_user,
+					proofs.clone(),
+					*expiry,
+				)
+				.map_err(|e| {
+					super::info!(
+						"unsigned claim by: {icon_address:?}. Rejected from pool. Reason: {e:?}"
+					);
+					e
+				})?;
+
+				// Never keep the claim in pool past it's expiry
+				let blocks_left: u64 = expiry
+					.saturating_sub(utils::get_current_block_number::<T>())
+					.unique_saturated_into();
+				let longevity = T::UnsignedClaimLongevity::get().min(blocks_left.saturating_add(1));
+
+				ValidTransaction::with_tag_prefix("AirdropClaim")
+					.priority(T::UnsignedClaimPriority::get())
+					.and_provides((campaign_id, icon_address))
+					.and_provides((campaign_id, ice_address))
+					.longevity(longevity)
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+
+	// implement all the helper function that are called from pallet dispatchable
+	impl<T: Config> Pallet<T> {
+		/// Validate and process single user claim request
+		/// Emit ClaimSuccess or PartialClaim event once transfer is made
+		pub fn do_user_claim(
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			ice_address: types::IceAddress,
+			message: types::RawPayload<T>,
+			icon_signature: types::IconSignature,
+			ice_signature: types::IceSignature,
+			total_amount: types::BalanceOf<T>,
+			defi_user: bool,
+			proofs: types::MerkleProofs<T>,
+			expiry: types::BlockNumberOf<T>,
+		) -> DispatchResult {
+			// Make sure targeted campaign is accepting claim
+			Self::ensure_campaign_active(campaign_id).map_err(|e| {
+				super::info!(
+					"claim request by: {icon_address:?}. Rejected at: ensure_campaign_active(). Campaign: {campaign_id}"
+				);
+				e
+			})?;
+
+			// Make sure same signed message is not used again
+			let payload_hash =
+				Self::ensure_not_replayed(campaign_id, &icon_address, message.as_ref(), expiry)
+					.map_err(|e| {
+						super::info!(
+							"claim request by: {icon_address:?}. Rejected at: ensure_not_replayed(). Error: {e:?}"
+						);
+						e
+					})?;
+
+			// Verify the integrity of message
+			Self::validate_message_payload(message.as_ref(), &ice_address).map_err(|e| {
+				super::info!(
+					"claim request by: {icon_address:?}. Rejected at: validate_message_payload(). Error: {e:?}"
+				);
+				e
+			})?;
+
+			// We expect a valid proof of this exchange call
+			Self::validate_merkle_proof(
+				campaign_id,
+				&icon_address,
+				total_amount,
+				defi_user,
+				proofs,
+			)
+			.map_err(|e| {
+				super::info!(
+					"claim request by: {icon_address:?}. Rejected at: validate_merkle_proof()"
+				);
+				e
+			})?;
+
+			// Validate icon signature
+			Self::validate_icon_address(&icon_address, &icon_signature, message.as_ref()).map_err(
+				|e| {
+					super::info!(
+						"claim request by: {icon_address:?}. Rejected at:  validate_icon_address()"
+					);
+					e
+				},
+			)?;
+
+			// Validate ice signature
+			Self::validate_ice_signature(&ice_signature, &icon_signature, &ice_address).map_err(
+				|e| {
+					super::info!(
+						"claim request by: {icon_address:?}. Rejected at: validate_ice_signature()"
+					);
+					e
+				},
+			)?;
+
+			// Now this address pair is verified,
+			// we can insert it to the map if this pair is new
+			Self::settle_user_claim(
+				campaign_id,
+				icon_address,
+				&ice_address,
+				total_amount,
+				defi_user,
+			)?;
+
+			Self::consume_payload(campaign_id, payload_hash);
+			Ok(())
+		}
+
+		/// Validate and process single user claim request whose
+		/// destination is an ice evm address
+		/// Emit ClaimSuccess or PartialClaim event once transfer is made
+		pub fn do_evm_user_claim(
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			evm_address: types::IceEvmAddress,
+			message: types::EvmRawPayload<T>,
+			icon_signature: types::IconSignature,
+			evm_signature: types::IceEvmSignature,
+			total_amount: types::BalanceOf<T>,
+			defi_user: bool,
+			proofs: types::MerkleProofs<T>,
+			expiry: types::BlockNumberOf<T>,
+		) -> DispatchResult {
+			// Make sure targeted campaign is accepting claim
+			Self::ensure_campaign_active(campaign_id).map_err(|e| {
+				super::info!(
+					"evm claim request by: {icon_address:?}. Rejected at: ensure_campaign_active(). Campaign: {campaign_id}"
+				);
+				e
+			})?;
+
+			// Make sure same signed message is not used again
+			let payload_hash =
+				Self::ensure_not_replayed(campaign_id, &icon_address, message.as_ref(), expiry)
+					.map_err(|e| {
+						super::info!(
+							"evm claim request by: {icon_address:?}. Rejected at: ensure_not_replayed(). Error: {e:?}"
+						);
+						e
+					})?;
+
+			// Verify the integrity of message
+			Self::validate_evm_message_payload(message.as_ref(), &evm_address).map_err(|e| {
+				super::info!(
+					"evm claim request by: {icon_address:?}. Rejected at: validate_evm_message_payload(). Error: {e:?}"
+				);
+				e
+			})?;
+
+			// We expect a valid proof of this exchange call
+			Self::validate_merkle_proof(
+				campaign_id,
+				&icon_address,
+				total_amount,
+				defi_user,
+				proofs,
+			)
+			.map_err(|e| {
+				super::info!(
+					"evm claim request by: {icon_address:?}. Rejected at: validate_merkle_proof()"
+				);
+				e
+			})?;
+
+			// Validate icon signature
+			Self::validate_icon_address(&icon_address, &icon_signature, message.as_ref()).map_err(
+				|e| {
+					super::info!(
+					"evm claim request by: {icon_address:?}. Rejected at: validate_icon_address()"
+				);
+					e
+				},
+			)?;
+
+			// Validate evm signature
+			Self::validate_evm_signature(&evm_signature, &icon_signature, &evm_address).map_err(
+				|e| {
+					super::info!(
+						"evm claim request by: {icon_address:?}. Rejected at: validate_evm_signature()"
+					);
+					e
+				},
+			)?;
+
+			// Fund goes to the substrate account this evm address is mapped into
+			let ice_address: types::IceAddress = utils::into_account_id(evm_address).into();
+			Self::settle_user_claim(
+				campaign_id,
+				icon_address,
+				&ice_address,
+				total_amount,
+				defi_user,
+			)?;
+
+			Self::consume_payload(campaign_id, payload_hash);
+			Ok(())
+		}
+
+		/// Record snapshot of already validated claim and transfer
+		/// the claimable amount to given ice address
+		fn settle_user_claim(
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			ice_address: &types::IceAddress,
+			total_amount: types::BalanceOf<T>,
+			defi_user: bool,
+		) -> DispatchResult {
+			let mut snapshot = Self::insert_or_get_snapshot(
+				campaign_id,
+				&icon_address,
+				ice_address,
+				defi_user,
+				total_amount,
+			)
+			.map_err(|e| {
+						super::info!("claim request by: {icon_address:?}. Rejected at: insert_or_get_snapshot. error: {e:?}");
+						e
+					})?;
+
+			// Make sure this user is eligible for claim.
+			Self::ensure_claimable(&snapshot).map_err(|e| {
+				super::info!("claim request by: {icon_address:?}. Rejected at: ensure_claimable(). Snapshot: {snapshot:?}.");
+				e
+			})?;
+
+			// We also make sure creditor have enough fund to complete this airdrop
+			Self::validate_creditor_fund(campaign_id, total_amount).map_err(|e| {
+				super::error!("claim request by: {icon_address:?}. Rejected at: validate_creditor_fund(). Amount: {total_amount:?}");
+				e
+			})?;
+
+			// Do the actual transfer if eligible
+			Self::do_transfer(campaign_id, &mut snapshot, &icon_address).map_err(|e| {
+				super::error!("claim request by: {icon_address:?}. Failed at: do_transfer(). Reason: {e:?}. Snapshot: {snapshot:?}");
+				e
+			})?;
+
+			Ok(())
+		}
+
+		/// Run all the stateless check of user claim along with
+		/// claimable status so that invalid unsigned claim never
+		/// reach the block
+		pub fn validate_unsigned_claim(
+			campaign_id: types::CampaignId,
+			icon_address: &types::IconAddress,
+			ice_address: &types::IceAddress,
+			message: &types::RawPayload<T>,
+			icon_signature: &types::IconSignature,
+			ice_signature: &types::IceSignature,
+			total_amount: types::BalanceOf<T>,
+			defi_user: bool,
+			proofs: types::MerkleProofs<T>,
+			expiry: types::BlockNumberOf<T>,
+		) -> Result<(), InvalidTransaction> {
+			Self::ensure_user_claim_switch().map_err(|_| InvalidTransaction::Call)?;
+			Self::ensure_campaign_active(campaign_id).map_err(|_| InvalidTransaction::Call)?;
+			Self::ensure_not_replayed(campaign_id, icon_address, message.as_ref(), expiry)
+				.map_err(|_| InvalidTransaction::Stale)?;
+
+			Self::validate_message_payload(message.as_ref(), ice_address)
+				.map_err(|_| InvalidTransaction::BadProof)?;
+			Self::validate_merkle_proof(campaign_id, icon_address, total_amount, defi_user, proofs)
+				.map_err(|_| InvalidTransaction::BadProof)?;
+			Self::validate_icon_address(icon_address, icon_signature, message.as_ref())
+				.map_err(|_| InvalidTransaction::BadProof)?;
+			Self::validate_ice_signature(ice_signature, icon_signature, ice_address)
+				.map_err(|_| InvalidTransaction::BadProof)?;
+
+			if let Some(snapshot) = Self::get_icon_snapshot_map(campaign_id, icon_address) {
+				Self::ensure_claimable(&snapshot).map_err(|_| InvalidTransaction::Stale)?;
+			}
+
+			// Claim that creditor cannot pay out would only fail in the block
+			Self::validate_creditor_fund(campaign_id, total_amount)
+				.map_err(|_| InvalidTransaction::Payment)?;
+
+			Ok(())
+		}
+
+		/// Check weather node is set to block incoming claim request
+		/// Return error in that case else return Ok
+		pub fn ensure_user_claim_switch() -> DispatchResult {
+			let is_disabled = Self::get_airdrop_state().block_claim_request;
+
+			if is_disabled {
+				Err(Error::<T>::NewClaimRequestBlocked.into())
+			} else {
+				Ok(())
+			}
+		}
+
+		/// Make sure given icon address have not been revoked
+		pub fn ensure_not_revoked(icon_address: &types::IconAddress) -> Result<(), Error<T>> {
+			ensure!(
+				!<RevokedIconAddresses<T>>::contains_key(icon_address),
+				Error::<T>::IconAddressRevoked
+			);
+			Ok(())
+		}
+
+		/// Make sure claim is made before it's expiry by an icon address
+		/// that is not revoked and signed payload is not consumed before
+		/// in this campaign. Return hash under which payload is consumed.
+		///
+		/// `expiry` is chosen by submitter and not covered by any signature
+		/// so it can only shorten the validity. Signed message that carry
+		/// the time it was signed at stop being accepted once
+		/// `SignedClaimValidity` have passed since then. Message with zero
+		/// timestamp, as signed by current frontends, is not bound by time
+		pub fn ensure_not_replayed(
+			campaign_id: types::CampaignId,
+			icon_address: &types::IconAddress,
+			payload: &[u8],
+			expiry: types::BlockNumberOf<T>,
+		) -> Result<types::PayloadHash, Error<T>> {
+			ensure!(
+				utils::get_current_block_number::<T>() <= expiry,
+				Error::<T>::ClaimExpired
+			);
+			let signed_at = T::SourceChainVerifier::signed_at(payload)
+				.ok_or(Error::<T>::InvalidMessagePayload)?;
+			if signed_at != 0 {
+				let now: u64 = T::UnixTime::now().as_millis().unique_saturated_into();
+				ensure!(
+					now <= signed_at.saturating_add(T::SignedClaimValidity::get()),
+					Error::<T>::ClaimExpired
+				);
+			}
+			Self::ensure_not_revoked(icon_address)?;
+
+			let payload_hash = sp_io::hashing::blake2_256(payload);
+			ensure!(
+				!<ConsumedPayloads<T>>::contains_key(campaign_id, payload_hash),
+				Error::<T>::PayloadAlreadyUsed
+			);
+
+			Ok(payload_hash)
+		}
+
+		/// Remove payloads consumed in first campaign waiting in
+		/// `PayloadsToClear`, at most `PAYLOADS_CLEARED_PER_BLOCK` of them
+		pub fn clear_consumed_payloads() -> Weight {
+			let weight = T::DbWeight::get().reads(1);
+			let campaign_id = match <PayloadsToClear<T>>::iter_keys().next() {
+				Some(campaign_id) => campaign_id,
+				None => return weight,
+			};
+
+			let removal =
+				<ConsumedPayloads<T>>::clear_prefix(campaign_id, PAYLOADS_CLEARED_PER_BLOCK, None);
+			let mut writes = removal.unique as u64;
+			if removal.maybe_cursor.is_none() {
+				<PayloadsToClear<T>>::remove(campaign_id);
+				writes += 1;
+			}
+
+			weight.saturating_add(T::DbWeight::get().reads_writes(removal.loops as u64, writes))
+		}
+
+		/// Record that payload of given hash have been used to claim
+		pub fn consume_payload(campaign_id: types::CampaignId, payload_hash: types::PayloadHash) {
+			<ConsumedPayloads<T>>::insert(
+				campaign_id,
+				payload_hash,
+				utils::get_current_block_number::<T>(),
+			);
+		}
+
+		pub fn get_campaign(
+			campaign_id: types::CampaignId,
+		) -> Result<types::CampaignInfo<T>, Error<T>> {
+			Self::try_get_campaign(campaign_id).ok_or(Error::<T>::NoSuchCampaign)
+		}
+
+		pub fn get_creditor_account(
+			campaign_id: types::CampaignId,
+		) -> Result<types::AccountIdOf<T>, Error<T>> {
+			Self::get_campaign(campaign_id).map(|campaign| campaign.creditor)
+		}
+
+		pub fn get_merkle_root(campaign_id: types::CampaignId) -> Result<[u8; 32], Error<T>> {
+			Self::get_campaign(campaign_id).map(|campaign| campaign.merkle_root)
+		}
+
+		/// Compute the outcome of claiming given amount without
+		/// modifying any storage. Used by runtime api
+		pub fn preview_claim(
+			campaign_id: types::CampaignId,
+			icon_address: types::IconAddress,
+			amount: types::BalanceOf<T>,
+			defi_user: bool,
+		) -> Result<types::ClaimPreviewOf<T>, DispatchError> {
+			let campaign = Self::get_campaign(campaign_id)?;
+			let snapshot = Self::get_icon_snapshot_map(campaign_id, icon_address);
+
+			// Snapshot that have been split once keep using the same terms
+			let vesting_terms = snapshot
+				.as_ref()
+				.and_then(|snapshot| snapshot.vesting_terms)
+				.unwrap_or(campaign.vesting_terms);
+
+			let (instant_amount, schedule, remainder) =
+				transfer::split_amount::<T>(amount, defi_user, &vesting_terms)?;
+
+			let to_balance = |amount: types::VestingBalanceOf<T>| {
+				<T::BalanceTypeConversion as Convert<
+					types::VestingBalanceOf<T>,
+					types::BalanceOf<T>,
+				>>::convert(amount)
+			};
+			let vesting_schedule =
+				schedule.map(
+					|schedule| pallet_airdrop_runtime_api::VestingSchedulePreview {
+						locked: to_balance(schedule.locked()),
+						per_block: to_balance(schedule.per_block()),
+						starting_block: schedule.starting_block(),
+					},
+				);
+
+			let already_claimed = snapshot
+				.as_ref()
+				.map(|snapshot| Self::ensure_claimable(snapshot).is_err())
+				.unwrap_or(false);
+
+			Ok(pallet_airdrop_runtime_api::ClaimPreview {
+				instant_amount,
+				vesting_schedule,
+				remainder,
+				already_claimed,
+				snapshot: snapshot.as_ref().map(types::SnapshotInfo::to_preview),
+			})
+		}
+
+		/// Make sure given campaign exists, is open and current block
+		/// lies within the period of this campaign
+		pub fn ensure_campaign_active(
+			campaign_id: types::CampaignId,
+		) -> Result<types::CampaignInfo<T>, Error<T>> {
+			let campaign = Self::get_campaign(campaign_id)?;
+			let current_block = utils::get_current_block_number::<T>();
+
+			ensure!(
+				campaign.state == types::CampaignState::Open,
+				Error::<T>::CampaignClosed
+			);
+			ensure!(
+				campaign_id != 0 || !migrations::v2::is_migrating::<T>(),
+				Error::<T>::SnapshotMigrationOngoing
+			);
+			ensure!(
+				current_block >= campaign.start_block,
+				Error::<T>::CampaignNotStarted
+			);
+			if let Some(end_block) = campaign.end_block {
+				ensure!(current_block <= end_block, Error::<T>::CampaignEnded);
+			}
+
+			Ok(campaign)
+		}
+
+		/// Check weather node is set to block incoming exchange request
+		/// Return error in that case else return Ok
+		pub fn ensure_exchange_claim_switch() -> DispatchResult {
+			let is_disabled = Self::get_airdrop_state().block_exchange_request;
+
+			if is_disabled {
+				Err(Error::<T>::NewExchangeRequestBlocked.into())
+			} else {
+				Ok(())
+			}
+		}
+
+		/// Helper function to create similar interface like `ensure_root`
+		/// but which instead check for server key
+		pub fn ensure_root_or_server(origin: OriginFor<T>) -> DispatchResult {
+			let is_root = ensure_root(origin.clone()).is_ok();
+			let is_offchain = {
+				let signed = ensure_signed(origin);
+				signed.is_ok() && signed.ok() == Self::get_airdrop_server_account()
+			};
+
+			ensure!(is_root || is_offchain, DispatchError::BadOrigin);
+			Ok(())
+		}
+
+		// Insert this address pair if it is new
+		pub fn insert_or_get_snapshot(
+			campaign_id: types::CampaignId,
+			icon_address: &types::IconAddress,
+			ice_address: &types::IceAddress,
+			defi_user: bool,
+			amount: types::BalanceOf<T>,
 		) -> Result<types::SnapshotInfo<T>, DispatchError> {
 			let ice_account =
 				Self::convert_to_account_id(ice_address.to_vec().try_into().map_err(|_| {
@@ -515,7 +1899,7 @@ pub mod pallet {
 					Error::<T>::IncompatibleAccountId
 				})?;
 
-			let old_snapshot = Self::get_icon_snapshot_map(&icon_address);
+			let old_snapshot = Self::get_icon_snapshot_map(campaign_id, &icon_address);
 			let old_icon_address = Self::get_ice_to_icon_map(&ice_account);
 
 			if let Some(old_icon_address) = old_icon_address {
@@ -542,7 +1926,7 @@ pub mod pallet {
 				let new_snapshot =
 					types::SnapshotInfo::<T>::new(ice_account.clone(), defi_user, amount);
 
-				<IconSnapshotMap<T>>::insert(icon_address, &new_snapshot);
+				<IconSnapshotMap<T>>::insert(campaign_id, icon_address, &new_snapshot);
 
 				new_snapshot
 			});
@@ -560,19 +1944,167 @@ pub mod pallet {
 			}
 		}
 
-		pub fn validate_creditor_fund(required_amount: types::BalanceOf<T>) -> DispatchResult {
+		pub fn validate_creditor_fund(
+			campaign_id: types::CampaignId,
+			required_amount: types::BalanceOf<T>,
+		) -> DispatchResult {
 			let creditor_balance =
-				<T as Config>::Currency::free_balance(&Self::get_creditor_account()?);
+				<T as Config>::Currency::free_balance(&Self::get_creditor_account(campaign_id)?);
 			let existential_deposit = <T as Config>::Currency::minimum_balance();
 
-			if creditor_balance > required_amount + existential_deposit {
+			if creditor_balance > required_amount.saturating_add(existential_deposit) {
 				Ok(())
 			} else {
-				Self::deposit_event(Event::<T>::CreditorBalanceLow);
 				Err(Error::<T>::InsufficientCreditorBalance.into())
 			}
 		}
 
+		/// All exchange accounts that are yet to make their claim
+		pub fn pending_exchange_claims(
+		) -> Vec<pallet_airdrop_runtime_api::PendingExchangeClaim<types::BalanceOf<T>>> {
+			<ExchangeAccountsMap<T>>::iter()
+				.map(
+					|(icon_address, amount)| pallet_airdrop_runtime_api::PendingExchangeClaim {
+						icon_address: icon_address.into(),
+						amount,
+					},
+				)
+				.collect()
+		}
+
+		/// Move refill amount from top-up source into creditor of given
+		/// campaign if it is below low water mark and allowance is left.
+		/// Return the weight consumed
+		pub fn refill_creditor(campaign_id: types::CampaignId) -> Weight {
+			// Closed campaign do not spend from creditor so it is
+			// neither refilled nor alerted until opened again
+			let campaign = match Self::try_get_campaign(campaign_id) {
+				Some(campaign) if campaign.state == types::CampaignState::Open => campaign,
+				_ => return T::DbWeight::get().reads(1),
+			};
+			let mut funding = match Self::get_creditor_funding(campaign_id) {
+				Some(funding) => funding,
+				None => return T::DbWeight::get().reads(2),
+			};
+			// Campaign, funding and creditor account
+			let mut reads = 3_u64;
+			let mut writes = 0_u64;
+
+			let mut balance = <T as Config>::Currency::free_balance(&campaign.creditor);
+			let refill_amount = funding.refill_amount.min(funding.allowance);
+
+			if balance < funding.low_water_mark && !refill_amount.is_zero() {
+				let source = T::CreditorTopUpSource::get();
+				// Source account is read and both accounts are written
+				reads += 1;
+				writes += 2;
+
+				let transfer_res = <T as Config>::Currency::transfer(
+					&source,
+					&campaign.creditor,
+					refill_amount,
+					ExistenceRequirement::KeepAlive,
+				);
+				match transfer_res {
+					Ok(()) => {
+						funding.allowance = funding.allowance.saturating_sub(refill_amount);
+						balance = balance.saturating_add(refill_amount);
+						<CreditorFunding<T>>::insert(campaign_id, &funding);
+						// Funding and the event
+						writes += 3;
+
+						super::info!(
+							"Creditor of campaign {campaign_id} refilled with {refill_amount:?}. Remaining allowance: {remaining:?}",
+							remaining = funding.allowance
+						);
+						Self::deposit_event(Event::<T>::CreditorToppedUp {
+							campaign_id,
+							amount: refill_amount,
+							remaining_allowance: funding.allowance,
+						});
+					}
+					Err(err) => {
+						super::error!(
+							"Failed to refill creditor of campaign {campaign_id} from {source:?}. Reason: {err:?}"
+						);
+					}
+				}
+			}
+
+			Self::check_creditor_balance(campaign_id, funding.low_water_mark, balance);
+			// Low flag and the alert event
+			reads += 1;
+			writes += 3;
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Emit `CreditorBalanceLow` once when given balance crosses below
+		/// low water mark. Alert is made again only after balance have
+		/// gone back above the mark
+		pub fn check_creditor_balance(
+			campaign_id: types::CampaignId,
+			low_water_mark: types::BalanceOf<T>,
+			balance: types::BalanceOf<T>,
+		) {
+			let is_low = balance < low_water_mark;
+			if is_low == Self::is_creditor_low(campaign_id) {
+				return;
+			}
+
+			<CreditorBalanceIsLow<T>>::insert(campaign_id, is_low);
+			if is_low {
+				super::info!(
+					"Creditor of campaign {campaign_id} is low. Balance: {balance:?}. Low water mark: {low_water_mark:?}"
+				);
+				Self::deposit_event(Event::<T>::CreditorBalanceLow {
+					campaign_id,
+					balance,
+					low_water_mark,
+				});
+			}
+		}
+
+		/// Snapshots whose vesting is yet to be applied after instant
+		/// transfer have been made. At most `limit` snapshots stored
+		/// after `start_key` are scanned
+		pub fn partial_snapshots(
+			start_key: Option<pallet_airdrop_runtime_api::SnapshotKey>,
+			limit: u32,
+		) -> types::PartialSnapshotsPageOf<T> {
+			let limit = limit.clamp(1, pallet_airdrop_runtime_api::MAX_SCANNED_SNAPSHOTS);
+			let start_key = start_key.map(|key| {
+				<IconSnapshotMap<T>>::hashed_key_for(key.campaign_id, key.icon_address.0)
+			});
+			let mut iter = match start_key {
+				Some(raw_key) => <IconSnapshotMap<T>>::iter_from(raw_key),
+				None => <IconSnapshotMap<T>>::iter(),
+			};
+
+			let mut snapshots = Vec::new();
+			let mut last_key = None;
+			for (campaign_id, icon_address, snapshot) in iter.by_ref().take(limit as usize) {
+				if snapshot.is_partial() {
+					snapshots.push(pallet_airdrop_runtime_api::PartialSnapshot {
+						campaign_id,
+						icon_address: icon_address.into(),
+						snapshot: snapshot.to_preview(),
+					});
+				}
+				last_key = Some(pallet_airdrop_runtime_api::SnapshotKey {
+					campaign_id,
+					icon_address: icon_address.into(),
+				});
+			}
+
+			// Continue from last scanned snapshot only if any is left
+			let next_key = iter.next().and(last_key);
+			pallet_airdrop_runtime_api::PartialSnapshotsPage {
+				snapshots,
+				next_key,
+			}
+		}
+
 		pub fn validate_whitelisted(
 			icon_address: &types::IconAddress,
 		) -> Result<types::BalanceOf<T>, Error<T>> {
@@ -584,22 +2116,22 @@ pub mod pallet {
 			signature: &types::IconSignature,
 			payload: &[u8],
 		) -> Result<(), Error<T>> {
-			let recovered_key = utils::recover_address(signature, payload)?;
+			let recovered_address = T::SourceChainVerifier::recover_signer(signature, payload)?;
 			ensure!(
-				recovered_key == icon_address.as_slice(),
+				&recovered_address == icon_address,
 				Error::<T>::InvalidSignature
 			);
 			Ok(())
 		}
 
 		pub fn validate_ice_signature(
-			signature_raw: &[u8; 64],
+			signature: &types::IceSignature,
 			msg: &[u8],
 			ice_bytes: &types::IceAddress,
 		) -> Result<bool, Error<T>> {
 			let wrapped_msg = utils::wrap_bytes(msg);
 
-			let is_valid = Self::check_signature(signature_raw, &wrapped_msg, ice_bytes);
+			let is_valid = Self::check_signature(signature, &wrapped_msg, ice_bytes);
 			if is_valid {
 				Ok(true)
 			} else {
@@ -611,8 +2143,9 @@ pub mod pallet {
 			payload: &[u8],
 			ice_address: &[u8; 32],
 		) -> Result<(), Error<T>> {
-			let extracted_address = utils::extract_ice_address(payload, ice_address)
-				.map_err(|_e| Error::<T>::FailedExtractingIceAddress)?;
+			let extracted_address =
+				T::SourceChainVerifier::extract_destination(payload, ice_address.len())
+					.ok_or(Error::<T>::FailedExtractingIceAddress)?;
 			ensure!(
 				extracted_address == ice_address,
 				Error::<T>::InvalidMessagePayload
@@ -620,14 +2153,59 @@ pub mod pallet {
 			Ok(())
 		}
 
+		pub fn validate_evm_message_payload(
+			payload: &[u8],
+			evm_address: &types::IceEvmAddress,
+		) -> Result<(), Error<T>> {
+			let extracted_address =
+				T::SourceChainVerifier::extract_destination(payload, evm_address.as_bytes().len())
+					.ok_or(Error::<T>::FailedExtractingIceAddress)?;
+			ensure!(
+				extracted_address == evm_address.as_bytes(),
+				Error::<T>::InvalidMessagePayload
+			);
+			Ok(())
+		}
+
+		pub fn validate_evm_signature(
+			signature: &types::IceEvmSignature,
+			msg: &[u8],
+			evm_address: &types::IceEvmAddress,
+		) -> Result<(), Error<T>> {
+			let recovered_address = utils::recover_evm_address(signature, msg)
+				.ok_or(Error::<T>::InvalidEvmSignature)?;
+			ensure!(
+				&recovered_address == evm_address,
+				Error::<T>::InvalidEvmSignature
+			);
+			Ok(())
+		}
+
+		/// Verify signature of any supported scheme against given account.
+		/// For ecdsa, account is the blake2 hash of compressed public key
 		pub fn check_signature(
-			signature_raw: &[u8; 64],
+			signature: &types::IceSignature,
 			msg: &[u8],
 			account_bytes: &[u8; 32],
 		) -> bool {
-			let signature = sp_core::sr25519::Signature::from_raw(*signature_raw);
-			let public = sp_core::sr25519::Public::from_raw(*account_bytes);
-			signature.verify(msg, &public)
+			let account = sp_runtime::AccountId32::new(*account_bytes);
+			signature.verify(msg, &account)
+		}
+
+		/// Weight of verifying given ice signature on top of the
+		/// sr25519 verification already accounted in claim benchmarks
+		pub fn ice_signature_extra_weight(signature: &types::IceSignature) -> Weight {
+			let sr25519_weight = T::AirdropWeightInfo::validate_ice_signature_sr25519();
+			let weight = match signature {
+				sp_runtime::MultiSignature::Sr25519(_) => sr25519_weight,
+				sp_runtime::MultiSignature::Ed25519(_) => {
+					T::AirdropWeightInfo::validate_ice_signature_ed25519()
+				}
+				sp_runtime::MultiSignature::Ecdsa(_) => {
+					T::AirdropWeightInfo::validate_ice_signature_ecdsa()
+				}
+			};
+			weight.saturating_sub(sr25519_weight)
 		}
 
 		pub fn get_bounded_proofs(
@@ -639,14 +2217,19 @@ pub mod pallet {
 		}
 
 		pub fn validate_merkle_proof(
+			campaign_id: types::CampaignId,
 			icon_address: &types::IconAddress,
 			amount: types::BalanceOf<T>,
 			defi_user: bool,
 			proof_hashes: types::MerkleProofs<T>,
 		) -> DispatchResult {
 			let amount = types::from_balance::<T>(amount);
-			let leaf_hash = merkle::hash_leaf(icon_address, amount, defi_user);
-			let merkle_root = Self::get_merkle_root()?;
+			let leaf_hash = merkle::hash_leaf::<T::MerkleLeafEncoder, T::MerkleNodeHasher>(
+				icon_address,
+				amount,
+				defi_user,
+			);
+			let merkle_root = Self::get_merkle_root(campaign_id)?;
 
 			let is_valid_proof =
 				<T as Config>::MerkelProofValidator::validate(leaf_hash, merkle_root, proof_hashes);
@@ -657,6 +2240,33 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Compute leaf hash and root of given proofs without validating
+		/// anything. Used by runtime api to diagnose invalid proofs
+		pub fn dry_run_merkle_proof(
+			campaign_id: types::CampaignId,
+			icon_address: &types::IconAddress,
+			amount: types::BalanceOf<T>,
+			defi_user: bool,
+			proof_hashes: Vec<types::MerkleHash>,
+		) -> Result<pallet_airdrop_runtime_api::MerkleProofCheck, DispatchError> {
+			let stored_root = Self::get_merkle_root(campaign_id)?;
+
+			let amount = types::from_balance::<T>(amount);
+			let leaf_hash = merkle::hash_leaf::<T::MerkleLeafEncoder, T::MerkleNodeHasher>(
+				icon_address,
+				amount,
+				defi_user,
+			);
+			let computed_root = merkle::proof_root::<T::MerkleNodeHasher>(leaf_hash, proof_hashes);
+
+			Ok(pallet_airdrop_runtime_api::MerkleProofCheck {
+				leaf_hash: leaf_hash.into(),
+				computed_root: computed_root.into(),
+				stored_root: stored_root.into(),
+				is_valid: computed_root == stored_root,
+			})
+		}
+
 		pub fn convert_to_account_id(
 			ice_bytes: [u8; 32],
 		) -> Result<types::AccountIdOf<T>, Error<T>> {
@@ -664,17 +2274,84 @@ pub mod pallet {
 				.map_err(|_e| Error::<T>::InvalidIceAddress)
 		}
 
+		/// Transfer claimable amount of given snapshot and
+		/// report the outcome as event
 		pub fn do_transfer(
+			campaign_id: types::CampaignId,
 			snapshot: &mut types::SnapshotInfo<T>,
 			icon_address: &types::IconAddress,
-		) -> Result<(), DispatchError> {
-			let transfer_result = transfer::do_transfer(snapshot);
+		) -> Result<transfer::TransferOutcome<T>, DispatchError> {
+			let campaign = Self::get_campaign(campaign_id)?;
+
+			let balance_before = <T as Config>::Currency::free_balance(&campaign.creditor);
+			let transfer_result = transfer::do_transfer(snapshot, &campaign);
+			let balance_after = <T as Config>::Currency::free_balance(&campaign.creditor);
+			if let Some(funding) = Self::get_creditor_funding(campaign_id) {
+				Self::check_creditor_balance(campaign_id, funding.low_water_mark, balance_after);
+			}
+
+			// Even failed transfer might have moved fund partially
+			// so account whatever actually left the creditor
+			let transferred = balance_before.saturating_sub(balance_after);
+			<DistributedAmount<T>>::mutate(campaign_id, |distributed| {
+				*distributed = distributed.saturating_add(transferred)
+			});
 
 			// No matter the result we will write the updated_snapshot
-			<IconSnapshotMap<T>>::insert(icon_address, snapshot);
+			<IconSnapshotMap<T>>::insert(campaign_id, icon_address, snapshot);
+
+			// Now snapshot have been written, report the result
+			let outcome = transfer_result?;
+			let claimer = snapshot.ice_address.clone();
+			let block_number = utils::get_current_block_number::<T>();
+
+			match outcome.vesting_error {
+				None => {
+					let vesting_schedule = outcome.vesting_schedule;
+					let vested_amount = vesting_schedule
+						.map(|schedule| {
+							<T::BalanceTypeConversion as Convert<
+								types::VestingBalanceOf<T>,
+								types::BalanceOf<T>,
+							>>::convert(schedule.locked())
+						})
+						.unwrap_or_default();
+
+					Self::deposit_event(Event::<T>::ClaimSuccess {
+						campaign_id,
+						icon_address: *icon_address,
+						claimer,
+						instant_amount: outcome.instant_amount,
+						vested_amount,
+						vesting_schedule,
+						block_number,
+						skipped_phase: outcome.skipped_phase,
+					});
+				}
+				Some(error) => {
+					if let Some(vesting_schedule) = outcome.vesting_schedule {
+						Self::deposit_event(Event::<T>::VestingFailed {
+							campaign_id,
+							icon_address: *icon_address,
+							claimer: claimer.clone(),
+							vesting_schedule,
+							error,
+						});
+					}
+
+					Self::deposit_event(Event::<T>::PartialClaim {
+						campaign_id,
+						icon_address: *icon_address,
+						claimer,
+						instant_amount: outcome.instant_amount,
+						block_number,
+						skipped_phase: outcome.skipped_phase,
+						error,
+					});
+				}
+			}
 
-			// Now snapshot have been written, return result
-			transfer_result
+			Ok(outcome)
 		}
 	}
 
@@ -685,11 +2362,18 @@ pub mod pallet {
 			<T as Config>::Currency::make_free_balance_be(account, amount);
 		}
 
-		pub fn set_creditor_account(new_account: sp_core::sr25519::Public) {
-			let mut account_bytes = new_account.0.clone();
-			let account = T::AccountId::decode(&mut &account_bytes[..]).unwrap();
-
-			<CreditorAccount<T>>::set(Some(account.clone()));
+		pub fn init_campaign(
+			campaign_id: types::CampaignId,
+			creditor: sp_core::sr25519::Public,
+			merkle_root: [u8; 32],
+			vesting_terms: types::VestingTerms,
+		) {
+			let account = T::AccountId::decode(&mut &creditor.0[..]).unwrap();
+			let campaign =
+				types::CampaignInfo::<T>::new(merkle_root, vesting_terms, account.clone());
+
+			<Campaigns<T>>::insert(campaign_id, campaign);
+			<CreditorCampaign<T>>::insert(account, campaign_id);
 		}
 	}
 
@@ -697,6 +2381,7 @@ pub mod pallet {
 	pub struct GenesisConfig<T: Config> {
 		pub creditor_account: types::AccountIdOf<T>,
 		pub merkle_root: [u8; 32],
+		pub vesting_terms: types::VestingTerms,
 	}
 
 	#[cfg(feature = "std")]
@@ -707,10 +2392,17 @@ pub mod pallet {
 			let creditor_account =
 				types::AccountIdOf::<T>::decode(&mut &creditor_account_hex[..]).unwrap();
 			let merkle_root = [0u8; 32];
+			// Transfer everything instantly unless configured otherwise
+			let vesting_terms = types::VestingTerms {
+				defi_instant_percentage: 100,
+				non_defi_instant_percentage: 100,
+				vesting_period: 0,
+			};
 
 			Self {
 				creditor_account,
 				merkle_root,
+				vesting_terms,
 			}
 		}
 	}
@@ -718,13 +2410,28 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			let exchange_accounts = exchange_accounts::get_exchange_account::<T>();
+			let exchange_accounts = exchange_accounts::get_exchange_account::<T>()
+				.expect("Exchange account balance must fit in runtime balance");
 			for (address, balance) in exchange_accounts {
 				<ExchangeAccountsMap<T>>::insert(address, balance);
 			}
 
-			CreditorAccount::<T>::put(&self.creditor_account);
-			MerkleRoot::<T>::put(&self.merkle_root);
+			assert!(
+				self.vesting_terms.is_valid(),
+				"Instant percentage of genesis vesting terms cannot exceed 100"
+			);
+
+			// Genesis values are used as first campaign
+			let campaign = types::CampaignInfo::<T>::new(
+				self.merkle_root,
+				self.vesting_terms,
+				self.creditor_account.clone(),
+			);
+			Campaigns::<T>::insert(0, campaign);
+			CreditorCampaign::<T>::insert(&self.creditor_account, 0);
+			NextCampaignId::<T>::put(1);
+
+			STORAGE_VERSION.put::<Pallet<T>>();
 		}
 	}
 }
@@ -5,7 +5,7 @@ use core::cmp::Ordering;
 use core::marker::PhantomData;
 use sp_io::hashing;
 use sp_std::prelude::*;
-use types::MerkelProofValidator;
+use types::{MerkelProofValidator, MerkleLeafEncoder, MerkleNodeHasher};
 
 pub struct AirdropMerkleValidator<T>(PhantomData<T>);
 
@@ -15,37 +15,96 @@ impl<T: Config> MerkelProofValidator<T> for AirdropMerkleValidator<T> {
 		root_hash: types::MerkleHash,
 		proofs: types::MerkleProofs<T>,
 	) -> bool {
-		let computed_root = hex::encode(proof_root(leaf_hash, proofs.to_vec()));
+		let computed_root = hex::encode(proof_root::<T::MerkleNodeHasher>(
+			leaf_hash,
+			proofs.to_vec(),
+		));
 		let root_hex = hex::encode(root_hash);
 
 		computed_root == root_hex
 	}
 }
 
-pub fn hash_leaf(
+/// Leaf as produced by icon snapshot:
+/// `icon_address ++ amount.to_string() ++ "0"/"1"`
+pub struct IconLeafEncoder;
+
+impl MerkleLeafEncoder for IconLeafEncoder {
+	fn encode_leaf(
+		icon_address: &types::IconAddress,
+		amount: types::ServerBalance,
+		defi_user: bool,
+	) -> Vec<u8> {
+		let defi_str = if defi_user { "1" } else { "0" };
+		let mut byte_vec = icon_address.to_vec();
+		byte_vec.extend_from_slice(amount.to_string().as_bytes());
+		byte_vec.extend_from_slice(defi_str.as_bytes());
+		byte_vec
+	}
+}
+
+/// Leaf as solidity `abi.encode(address, uint256, bool)`
+/// where each field is left padded to 32 bytes
+pub struct AbiLeafEncoder;
+
+impl MerkleLeafEncoder for AbiLeafEncoder {
+	fn encode_leaf(
+		icon_address: &types::IconAddress,
+		amount: types::ServerBalance,
+		defi_user: bool,
+	) -> Vec<u8> {
+		let mut byte_vec = sp_std::vec![0_u8; 96];
+		byte_vec[12..32].copy_from_slice(icon_address);
+		byte_vec[48..64].copy_from_slice(&amount.to_be_bytes());
+		byte_vec[95] = defi_user.into();
+		byte_vec
+	}
+}
+
+/// Blake2-256 tree as produced by icon snapshot
+pub struct Blake2MerkleHasher;
+
+impl MerkleNodeHasher for Blake2MerkleHasher {
+	fn hash(data: &[u8]) -> types::MerkleHash {
+		hashing::blake2_256(data)
+	}
+}
+
+/// Keccak-256 tree as produced by evm tooling
+pub struct KeccakMerkleHasher;
+
+impl MerkleNodeHasher for KeccakMerkleHasher {
+	fn hash(data: &[u8]) -> types::MerkleHash {
+		hashing::keccak_256(data)
+	}
+}
+
+pub fn hash_leaf<E: MerkleLeafEncoder, H: MerkleNodeHasher>(
 	icon_address: &types::IconAddress,
 	amount: types::ServerBalance,
 	defi_user: bool,
 ) -> [u8; 32] {
-	let defi_str = if defi_user { "1" } else { "0" };
-	let mut byte_vec = icon_address.to_vec();
-	byte_vec.extend_from_slice(amount.to_string().as_bytes());
-	byte_vec.extend_from_slice(defi_str.as_bytes());
-	hashing::blake2_256(&byte_vec)
+	H::hash(&E::encode_leaf(icon_address, amount, defi_user))
 }
 
-pub fn proof_root(leaf_hash: types::MerkleHash, proofs: Vec<types::MerkleHash>) -> [u8; 32] {
+pub fn proof_root<H: MerkleNodeHasher>(
+	leaf_hash: types::MerkleHash,
+	proofs: Vec<types::MerkleHash>,
+) -> [u8; 32] {
 	let mut one = leaf_hash;
 	for proof in proofs {
-		one = create_hash(one, proof);
+		one = create_hash::<H>(one, proof);
 	}
 
 	one
 }
 
-pub fn create_hash(one: types::MerkleHash, other: types::MerkleHash) -> [u8; 32] {
+pub fn create_hash<H: MerkleNodeHasher>(
+	one: types::MerkleHash,
+	other: types::MerkleHash,
+) -> [u8; 32] {
 	let sorted = sort_array(one, other, 0_usize);
-	hashing::blake2_256(&sorted)
+	H::hash(&sorted)
 }
 
 pub fn sort_array(one: types::MerkleHash, other: types::MerkleHash, pos: usize) -> Vec<u8> {
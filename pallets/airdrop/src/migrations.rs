@@ -0,0 +1,262 @@
+//! Storage migrations of airdrop pallet.
+//! Each migration lives in it's own module named after the storage version
+//! it migrates to and is expected to be added in runtime's `Executive`
+
+use crate as airdrop;
+use airdrop::{error, info, types, Config, Pallet};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Migrate from the single round layout to campaign based layout.
+///
+/// Before this version pallet had a single merkle root and creditor
+/// account, snapshots were keyed only by icon address and did not record
+/// the vesting terms they were claimed with. Pallet also tracked it's
+/// version in a custom `StorageVersion` value which is now removed in
+/// favour of FRAME storage version
+pub mod v2 {
+	use super::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	/// Layout of snapshot before vesting terms were recorded
+	#[derive(Encode, Decode, RuntimeDebugNoBound)]
+	pub struct OldSnapshotInfo<T: Config> {
+		pub ice_address: types::AccountIdOf<T>,
+		pub amount: types::BalanceOf<T>,
+		pub defi_user: bool,
+		pub done_instant: bool,
+		pub done_vesting: bool,
+		pub vesting_block_number: Option<types::BlockNumberOf<T>>,
+		pub instant_block_number: Option<types::BlockNumberOf<T>>,
+		pub initial_transfer: types::BalanceOf<T>,
+	}
+
+	#[frame_support::storage_alias]
+	pub type IconSnapshotMap<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128, types::IconAddress, OldSnapshotInfo<T>, OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type MerkleRoot<T: Config> = StorageValue<Pallet<T>, [u8; 32], OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type CreditorAccount<T: Config> =
+		StorageValue<Pallet<T>, types::AccountIdOf<T>, OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type StorageVersion<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+	impl<T: Config> OldSnapshotInfo<T> {
+		/// Convert into new layout. Snapshot that have already been
+		/// (partially) claimed was split with `vesting_terms`
+		pub fn migrate(self, vesting_terms: types::VestingTerms) -> types::SnapshotInfo<T> {
+			let is_touched = self.done_instant || self.done_vesting;
+
+			types::SnapshotInfo {
+				ice_address: self.ice_address,
+				amount: self.amount,
+				defi_user: self.defi_user,
+				done_instant: self.done_instant,
+				done_vesting: self.done_vesting,
+				vesting_block_number: self.vesting_block_number,
+				instant_block_number: self.instant_block_number,
+				initial_transfer: self.initial_transfer,
+				vesting_terms: is_touched.then_some(vesting_terms),
+			}
+		}
+
+		/// Amount that have left the creditor for this snapshot
+		fn distributed(&self) -> types::BalanceOf<T> {
+			let mut distributed = types::BalanceOf::<T>::default();
+			if self.done_instant {
+				distributed = self.initial_transfer;
+
+				// Schedule was only applied if vesting block is recorded
+				if self.vesting_block_number.is_some() {
+					distributed = self.amount;
+				}
+			}
+
+			distributed
+		}
+	}
+
+	/// Progress of moving legacy snapshots into campaign 0. Exists only
+	/// while there are `IceIconMap` entries left to visit
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct SnapshotCursor {
+		/// Raw key of `IceIconMap` entry visited last. Empty before first step
+		pub last_key: Vec<u8>,
+		/// Terms that already claimed snapshots were split with
+		pub vesting_terms: types::VestingTerms,
+	}
+
+	#[frame_support::storage_alias]
+	pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, SnapshotCursor, OptionQuery>;
+
+	/// Maximum number of `IceIconMap` entries visited in a single block
+	pub const SNAPSHOTS_PER_BLOCK: u32 = 64;
+
+	/// Whether legacy snapshots are still being moved into campaign 0.
+	/// Campaign 0 does not accept claims until this is done
+	pub fn is_migrating<T: Config>() -> bool {
+		MigrationCursor::<T>::exists()
+	}
+
+	/// Move next `limit` legacy snapshots into campaign 0.
+	///
+	/// Legacy map is hashed without concat so icon address can't be
+	/// recovered from it's key. IceIconMap hold every icon address that
+	/// ever made a claim and is walked instead, resuming from the key
+	/// recorded in `MigrationCursor`. Called from `on_initialize` every
+	/// block until whole map is visited
+	pub fn migrate_snapshots<T: Config>(limit: u32) -> Weight {
+		let mut cursor = match MigrationCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let mut reads = 1_u64;
+		let mut writes = 1_u64;
+
+		let mut iter = if cursor.last_key.is_empty() {
+			airdrop::IceIconMap::<T>::iter()
+		} else {
+			airdrop::IceIconMap::<T>::iter_from(cursor.last_key.clone())
+		};
+
+		let mut distributed = types::BalanceOf::<T>::default();
+		let mut visited = 0_u32;
+		let mut migrated = 0_u32;
+		while visited < limit {
+			let icon_address = match iter.next() {
+				Some((_ice_address, icon_address)) => icon_address,
+				None => break,
+			};
+			visited += 1;
+			reads += 2;
+
+			if let Some(old_snapshot) = IconSnapshotMap::<T>::take(icon_address) {
+				distributed = distributed.saturating_add(old_snapshot.distributed());
+				airdrop::IconSnapshotMap::<T>::insert(
+					0,
+					icon_address,
+					old_snapshot.migrate(cursor.vesting_terms),
+				);
+
+				writes += 2;
+				migrated += 1;
+			}
+		}
+
+		if !distributed.is_zero() {
+			airdrop::DistributedAmount::<T>::mutate(0, |amount| {
+				*amount = amount.saturating_add(distributed)
+			});
+			reads += 1;
+			writes += 1;
+		}
+
+		if visited < limit {
+			MigrationCursor::<T>::kill();
+			info!("Airdrop migration to v2: {migrated} snapshots moved. All snapshots are in campaign 0");
+		} else {
+			cursor.last_key = iter.last_raw_key().to_vec();
+			MigrationCursor::<T>::put(cursor);
+			info!("Airdrop migration to v2: {migrated} snapshots moved to campaign 0");
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Move all legacy single round storage into campaign 0.
+	/// `VestingTerms` must be the terms runtime used before this upgrade.
+	///
+	/// Only the single values are migrated here. Snapshots are moved by
+	/// `migrate_snapshots` over the following blocks
+	pub struct MigrateToV2<T, VestingTerms>(PhantomData<(T, VestingTerms)>);
+
+	impl<T: Config, VestingTerms: Get<types::VestingTerms>> OnRuntimeUpgrade
+		for MigrateToV2<T, VestingTerms>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 2 {
+				info!("Airdrop migration to v2 skipped. On chain version: {on_chain_version:?}");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let vesting_terms = VestingTerms::get();
+			let mut reads = 1_u64;
+			let mut writes = 0_u64;
+
+			let merkle_root = MerkleRoot::<T>::take();
+			let creditor = CreditorAccount::<T>::take();
+			reads += 2;
+			writes += 2;
+
+			match (merkle_root, creditor) {
+				(Some(merkle_root), Some(creditor)) => {
+					let campaign =
+						types::CampaignInfo::<T>::new(merkle_root, vesting_terms, creditor.clone());
+					airdrop::Campaigns::<T>::insert(0, campaign);
+					airdrop::CreditorCampaign::<T>::insert(creditor, 0);
+					writes += 2;
+				}
+				_ => {
+					error!(
+						"Airdrop migration to v2: merkle root or creditor was not set. Campaign 0 not created"
+					);
+				}
+			}
+
+			MigrationCursor::<T>::put(SnapshotCursor {
+				last_key: Vec::new(),
+				vesting_terms,
+			});
+			airdrop::NextCampaignId::<T>::put(1);
+			StorageVersion::<T>::kill();
+			Pallet::<T>::current_storage_version().put::<Pallet<T>>();
+			writes += 4;
+
+			info!(
+				"Airdrop migrated to v2. Snapshots will be moved to campaign 0 in following blocks"
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 2,
+				"Airdrop storage is already at v2"
+			);
+
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Airdrop storage version not updated to v2"
+			);
+			ensure!(
+				MigrationCursor::<T>::exists(),
+				"Snapshot migration to campaign 0 have not been scheduled"
+			);
+			ensure!(
+				airdrop::Campaigns::<T>::contains_key(0),
+				"Campaign 0 have not been created"
+			);
+			ensure!(
+				!MerkleRoot::<T>::exists() && !CreditorAccount::<T>::exists(),
+				"Legacy storage have not been cleaned"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,305 @@
+//! Offchain worker that relays user claims queued in offchain storage.
+//! Claims are queued through `airdrop_submitClaim` rpc, validated against
+//! current state and submitted in batch as signed transaction from the
+//! server account whose key is held in node keystore. Submitted claims
+//! are kept until they are seen completed on chain and queued again if
+//! their transaction expired without completing them
+
+use crate as airdrop;
+use airdrop::{error, info, types, Call, Config, Pallet};
+use frame_support::pallet_prelude::*;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use pallet_airdrop_runtime_api::{QueuedClaim, CLAIM_QUEUE_KEY};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+	traits::{Convert, IdentifyAccount},
+	RuntimeAppPublic,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Key type under which server key is stored in node keystore
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"aird");
+
+/// Key in persistent offchain storage under which submitted
+/// claims wait to be seen completed on chain
+pub const SUBMITTED_CLAIMS_KEY: &[u8] = b"airdrop::submitted-claims";
+
+/// Claim relayed by offchain worker along with the block it was submitted at
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SubmittedClaim<BlockNumber> {
+	pub claim: QueuedClaim,
+	pub submitted_at: BlockNumber,
+}
+
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Crypto used to sign claims relayed by offchain worker
+	pub struct AuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// Submit batch of claims prepared by offchain worker
+pub trait ClaimSubmitter<T: Config> {
+	/// Whether this node is able to submit claims at all.
+	/// Queue is left untouched otherwise
+	fn can_submit() -> bool;
+
+	/// Submit given batch. Return false if it could not be submitted
+	fn submit(claims: types::UserClaimBatch<T>) -> bool;
+}
+
+/// Offchain claim processing is disabled
+impl<T: Config> ClaimSubmitter<T> for () {
+	fn can_submit() -> bool {
+		false
+	}
+
+	fn submit(_claims: types::UserClaimBatch<T>) -> bool {
+		false
+	}
+}
+
+/// Submit claims as signed transaction from server account.
+/// Key of server account must be inserted in keystore as `KEY_TYPE`
+pub struct SignedClaimSubmitter<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> SignedClaimSubmitter<T, C>
+where
+	T: Config + CreateSignedTransaction<Call<T>>,
+	C: AppCrypto<T::Public, T::Signature>,
+{
+	/// Key in local keystore that belongs to current server account
+	fn server_key() -> Option<T::Public> {
+		let server_account = Pallet::<T>::get_airdrop_server_account()?;
+
+		C::RuntimeAppPublic::all()
+			.into_iter()
+			.map(|key| {
+				let generic_public: C::GenericPublic = key.into();
+				let public: T::Public = generic_public.into();
+				public
+			})
+			.find(|public| public.clone().into_account() == server_account)
+	}
+}
+
+impl<T, C> ClaimSubmitter<T> for SignedClaimSubmitter<T, C>
+where
+	T: Config + CreateSignedTransaction<Call<T>>,
+	C: AppCrypto<T::Public, T::Signature>,
+{
+	fn can_submit() -> bool {
+		Self::server_key().is_some()
+	}
+
+	fn submit(claims: types::UserClaimBatch<T>) -> bool {
+		let server_key = match Self::server_key() {
+			Some(key) => key,
+			None => {
+				error!("Key of airdrop server account not found in keystore");
+				return false;
+			}
+		};
+
+		let claim_count = claims.len();
+		let result = Signer::<T, C>::any_account()
+			.with_filter(sp_std::vec![server_key])
+			.send_signed_transaction(|_account| Call::dispatch_user_claims_batch {
+				claims: claims.clone(),
+			});
+
+		match result {
+			Some((_account, Ok(()))) => {
+				info!("Submitted batch of {claim_count} claims from offchain queue");
+				true
+			}
+			Some((_account, Err(()))) => {
+				error!("Failed to submit batch of {claim_count} claims from offchain queue");
+				false
+			}
+			None => {
+				error!("No account available to sign claims from offchain queue");
+				false
+			}
+		}
+	}
+}
+
+/// Convert queued claim into the request batch call expects.
+/// None if any of the field do not fit
+pub fn into_claim_request<T: Config>(claim: QueuedClaim) -> Option<types::UserClaimRequest<T>> {
+	Some(types::UserClaimRequest {
+		campaign_id: claim.campaign_id,
+		icon_address: claim.icon_address,
+		ice_address: claim.ice_address,
+		message: claim.message.try_into().ok()?,
+		icon_signature: claim.icon_signature,
+		ice_signature: claim.ice_signature,
+		amount: types::try_to_balance::<T>(claim.amount).ok()?,
+		defi_user: claim.defi_user,
+		proofs: claim.proofs.try_into().ok()?,
+		expiry: claim.expiry.into(),
+	})
+}
+
+/// Whether payload of given claim have already been used to claim
+fn is_completed<T: Config>(claim: &QueuedClaim) -> bool {
+	let payload_hash = sp_io::hashing::blake2_256(&claim.message);
+	Pallet::<T>::get_payload_consumption(claim.campaign_id, payload_hash).is_some()
+}
+
+/// Take a single batch out of offchain queue, drop the invalid claims
+/// and submit the rest. Claims are put back in queue if batch could
+/// not be submitted. Submitted claims that are not completed once their
+/// transaction can no longer be included are put back in queue too
+pub fn process_claim_queue<T: Config>() {
+	if !T::OffchainClaimSubmitter::can_submit() {
+		return;
+	}
+
+	let now = frame_system::Pallet::<T>::block_number();
+	let submitted_ref = StorageValueRef::persistent(SUBMITTED_CLAIMS_KEY);
+	let submitted = submitted_ref
+		.get::<Vec<SubmittedClaim<types::BlockNumberOf<T>>>>()
+		.unwrap_or_else(|_| {
+			error!("Submitted claims are corrupted. Discarding them");
+			None
+		})
+		.unwrap_or_default();
+	// Transaction is mortal so it can not be included once
+	// `BlockHashCount` blocks have passed since it's submission
+	let (pending, expired): (Vec<_>, Vec<_>) = submitted
+		.into_iter()
+		.filter(|submitted| !is_completed::<T>(&submitted.claim))
+		.partition(|submitted| {
+			now < submitted
+				.submitted_at
+				.saturating_add(T::BlockHashCount::get())
+		});
+
+	let queue_ref = StorageValueRef::persistent(CLAIM_QUEUE_KEY);
+	let batch_size = T::MaxClaimBatchSize::get() as usize;
+
+	let mut taken = Vec::new();
+	let take_res = queue_ref.mutate(
+		|queue: Result<Option<Vec<QueuedClaim>>, StorageRetrievalError>| {
+			let queue = queue
+				.unwrap_or_else(|_| {
+					error!("Offchain claim queue is corrupted. Discarding it");
+					None
+				})
+				.unwrap_or_default();
+
+			// Expired claims are retried first
+			let mut queue: Vec<QueuedClaim> = expired
+				.iter()
+				.map(|submitted| submitted.claim.clone())
+				.chain(queue)
+				.collect();
+			let rest = queue.split_off(queue.len().min(batch_size));
+			taken = queue;
+			Ok::<_, ()>(rest)
+		},
+	);
+	match take_res {
+		Ok(_) => {}
+		Err(MutateStorageError::ConcurrentModification(_)) => {
+			info!("Offchain claim queue modified concurrently. Retrying in next block");
+			return;
+		}
+		Err(MutateStorageError::ValueFunctionFailed(())) => return,
+	}
+	// Completed claims are done with and expired ones are back in queue
+	submitted_ref.set(&pending);
+
+	if taken.is_empty() {
+		return;
+	}
+
+	let mut valid_claims = Vec::with_capacity(taken.len());
+	let mut requests = Vec::with_capacity(taken.len());
+	for claim in taken {
+		let icon_address = claim.icon_address;
+		let request = match into_claim_request::<T>(claim.clone()) {
+			Some(request) => request,
+			None => {
+				error!("Dropped queued claim of {icon_address:?}. Reason: malformed request");
+				continue;
+			}
+		};
+
+		let validation = Pallet::<T>::validate_unsigned_claim(
+			request.campaign_id,
+			&request.icon_address,
+			&request.ice_address,
+			&request.message,
+			&request.icon_signature,
+			&request.ice_signature,
+			request.amount,
+			request.defi_user,
+			request.proofs.clone(),
+			request.expiry,
+		);
+		match validation {
+			Ok(()) => {
+				valid_claims.push(claim);
+				requests.push(request);
+			}
+			Err(err) => {
+				error!("Dropped queued claim of {icon_address:?}. Reason: {err:?}");
+			}
+		}
+	}
+
+	if requests.is_empty() {
+		return;
+	}
+
+	// Never more than batch size as taken claims are bounded by it
+	let batch = match types::UserClaimBatch::<T>::try_from(requests) {
+		Ok(batch) => batch,
+		Err(_) => return,
+	};
+	if T::OffchainClaimSubmitter::submit(batch) {
+		let track_res = submitted_ref.mutate(
+			|submitted: Result<Option<Vec<SubmittedClaim<_>>>, StorageRetrievalError>| {
+				let mut submitted = submitted.ok().flatten().unwrap_or_default();
+				submitted.extend(valid_claims.into_iter().map(|claim| SubmittedClaim {
+					claim,
+					submitted_at: now,
+				}));
+				Ok::<_, ()>(submitted)
+			},
+		);
+		if track_res.is_err() {
+			error!("Failed to keep track of submitted claims");
+		}
+		return;
+	}
+
+	// Put valid claims back in front so they are retried first
+	let restore_res = queue_ref.mutate(
+		|queue: Result<Option<Vec<QueuedClaim>>, StorageRetrievalError>| {
+			let queue = queue.ok().flatten().unwrap_or_default();
+			let mut restored = valid_claims;
+			restored.extend(queue);
+			Ok::<_, ()>(restored)
+		},
+	);
+	if restore_res.is_err() {
+		error!("Failed to put claims back in offchain queue");
+	}
+}
@@ -0,0 +1,183 @@
+//! Property style tests driving claim calls with arbitrary amounts,
+//! creditor balances and vesting terms. Any call is allowed to fail
+//! but none of them may panic
+use super::prelude::*;
+use crate::tests::UserClaimTestCase;
+use frame_support::traits::Currency;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Number of random inputs tried in each property
+const ROUNDS: usize = 200;
+
+/// Amounts around which arithmetic is most likely to go wrong
+const EDGE_AMOUNTS: &[u128] = &[
+	0,
+	1,
+	499,
+	500,
+	501,
+	1_000,
+	u32::MAX as u128,
+	u64::MAX as u128,
+	u128::MAX / 100,
+	u128::MAX / 100 + 1,
+	u128::MAX - 500,
+	u128::MAX - 1,
+	u128::MAX,
+];
+
+/// Deterministic xorshift generator so that failures can be reproduced
+struct Rng(u64);
+
+impl Rng {
+	fn next_u64(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	/// Random amount of random bit width so small values are common too
+	fn amount(&mut self) -> u128 {
+		let value = (self.next_u64() as u128) << 64 | self.next_u64() as u128;
+		value >> (self.next_u64() % 128)
+	}
+
+	fn vesting_terms(&mut self) -> types::VestingTerms {
+		types::VestingTerms {
+			defi_instant_percentage: (self.next_u64() % 101) as u8,
+			non_defi_instant_percentage: (self.next_u64() % 101) as u8,
+			vesting_period: match self.next_u64() % 4 {
+				0 => 0,
+				1 => 1,
+				2 => u32::MAX,
+				_ => self.next_u64() as u32,
+			},
+		}
+	}
+}
+
+/// Inputs of a single round: claimed amount, creditor balance and terms
+fn inputs(seed: u64) -> Vec<(u128, u128, types::VestingTerms)> {
+	let mut rng = Rng(seed);
+	let mut inputs = Vec::new();
+	for &amount in EDGE_AMOUNTS {
+		for &balance in EDGE_AMOUNTS {
+			inputs.push((amount, balance, mock::VESTING_TERMS));
+		}
+	}
+	for _ in 0..ROUNDS {
+		inputs.push((rng.amount(), rng.amount(), rng.vesting_terms()));
+	}
+	inputs
+}
+
+fn setup(creditor_balance: u128, terms: types::VestingTerms) {
+	run_to_block(1);
+	let creditor = force_get_creditor_account::<Test>();
+	<Test as pallet_airdrop::Config>::Currency::make_free_balance_be(&creditor, creditor_balance);
+	assert_ok!(AirdropModule::set_vesting_terms(
+		RuntimeOrigin::root(),
+		0,
+		terms
+	));
+}
+
+#[test]
+fn user_claim_never_panics() {
+	for (amount, creditor_balance, terms) in inputs(0x1ce) {
+		let outcome = catch_unwind(AssertUnwindSafe(|| {
+			minimal_test_ext().execute_with(|| {
+				setup(creditor_balance, terms);
+				let case = UserClaimTestCase::default();
+				let _ = AirdropModule::dispatch_user_claim(
+					RuntimeOrigin::root(),
+					0,
+					case.icon_address,
+					case.ice_address,
+					case.message,
+					case.icon_signature,
+					case.ice_signature,
+					amount,
+					case.defi_user,
+					case.merkle_proofs,
+					case.expiry,
+				);
+			})
+		}));
+		assert!(
+			outcome.is_ok(),
+			"User claim panicked. Amount: {amount}. Creditor: {creditor_balance}. Terms: {terms:?}"
+		);
+	}
+}
+
+#[test]
+fn exchange_claim_never_panics() {
+	for (amount, creditor_balance, terms) in inputs(0xc1a1) {
+		let outcome = catch_unwind(AssertUnwindSafe(|| {
+			minimal_test_ext().execute_with(|| {
+				setup(creditor_balance, terms);
+				let case = UserClaimTestCase::default();
+				pallet_airdrop::ExchangeAccountsMap::<Test>::insert(case.icon_address, amount);
+				let _ = AirdropModule::dispatch_exchange_claim(
+					RuntimeOrigin::root(),
+					0,
+					case.icon_address,
+					case.ice_address,
+					amount,
+					case.defi_user,
+					case.merkle_proofs,
+				);
+			})
+		}));
+		assert!(
+			outcome.is_ok(),
+			"Exchange claim panicked. Amount: {amount}. Creditor: {creditor_balance}. Terms: {terms:?}"
+		);
+	}
+}
+
+#[test]
+fn claim_over_zero_period_is_instant() {
+	minimal_test_ext().execute_with(|| {
+		setup(
+			10_000_0000,
+			types::VestingTerms {
+				defi_instant_percentage: 0,
+				non_defi_instant_percentage: 0,
+				vesting_period: 1,
+			},
+		);
+		let case = UserClaimTestCase::default();
+		assert_ok!(AirdropModule::dispatch_user_claim(
+			RuntimeOrigin::root(),
+			0,
+			case.icon_address,
+			case.ice_address,
+			case.message,
+			case.icon_signature,
+			case.ice_signature,
+			case.amount,
+			case.defi_user,
+			case.merkle_proofs,
+			case.expiry,
+		));
+
+		let claimer = AirdropModule::convert_to_account_id(case.ice_address).unwrap();
+		assert_eq!(
+			<Test as pallet_airdrop::Config>::Currency::free_balance(&claimer),
+			case.amount
+		);
+	});
+}
+
+#[test]
+fn fallible_conversions() {
+	minimal_test_ext().execute_with(|| {
+		assert!(matches!(
+			types::try_to_balance::<Test>(u128::MAX),
+			Ok(u128::MAX)
+		));
+	});
+}
@@ -0,0 +1,587 @@
+use super::prelude::*;
+use crate::{tests::UserClaimTestCase, Config};
+use frame_support::{dispatch::DispatchResultWithPostInfo, traits::Currency};
+
+fn claim_in(campaign_id: types::CampaignId, case: UserClaimTestCase) -> DispatchResultWithPostInfo {
+	AirdropModule::dispatch_user_claim(
+		RuntimeOrigin::root(),
+		campaign_id,
+		case.icon_address,
+		case.ice_address,
+		case.message,
+		case.icon_signature,
+		case.ice_signature,
+		case.amount,
+		case.defi_user,
+		case.merkle_proofs,
+		case.expiry,
+	)
+}
+
+/// Creditor of campaign created by `create_campaign_with`
+fn campaign_creditor(campaign_id: types::CampaignId) -> types::AccountIdOf<Test> {
+	types::AccountIdOf::<Test>::from_raw([0xc0 + campaign_id as u8; 32])
+}
+
+fn create_campaign_with(
+	merkle_root: [u8; 32],
+	start_block: types::BlockNumberOf<Test>,
+	end_block: Option<types::BlockNumberOf<Test>>,
+) -> types::CampaignId {
+	let campaign_id = AirdropModule::get_next_campaign_id();
+	let creditor = campaign_creditor(campaign_id);
+	<Test as Config>::Currency::make_free_balance_be(&creditor, 10_000_0000);
+	assert_ok!(AirdropModule::create_campaign(
+		RuntimeOrigin::root(),
+		merkle_root,
+		creditor,
+		mock::VESTING_TERMS,
+		start_block,
+		end_block,
+	));
+
+	campaign_id
+}
+
+#[test]
+fn genesis_campaign() {
+	minimal_test_ext().execute_with(|| {
+		let campaign = AirdropModule::get_campaign(0).unwrap();
+
+		assert_eq!(campaign.state, types::CampaignState::Open);
+		assert_eq!(campaign.vesting_terms, mock::VESTING_TERMS);
+		assert_eq!(campaign.end_block, None);
+		assert_eq!(AirdropModule::get_next_campaign_id(), 1);
+		assert_err!(AirdropModule::get_campaign(1), PalletError::NoSuchCampaign);
+	});
+}
+
+#[test]
+fn create_campaign() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		let merkle_root = [1u8; 32];
+
+		let campaign_id = create_campaign_with(merkle_root, 5, Some(10));
+		assert_eq!(campaign_id, 1);
+		assert_eq!(AirdropModule::get_next_campaign_id(), 2);
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::CampaignCreated {
+					campaign_id,
+					merkle_root
+				}
+				.into()
+			)
+		);
+
+		let campaign = AirdropModule::get_campaign(campaign_id).unwrap();
+		assert_eq!(campaign.merkle_root, merkle_root);
+		assert_eq!(campaign.start_block, 5);
+		assert_eq!(campaign.end_block, Some(10));
+
+		// Only root can create campaign
+		assert_err!(
+			AirdropModule::create_campaign(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[0]),
+				merkle_root,
+				force_get_creditor_account::<Test>(),
+				mock::VESTING_TERMS,
+				0,
+				None,
+			),
+			PalletError::DeniedOperation
+		);
+
+		// Instant percentage above 100 is rejected
+		let mut invalid_terms = mock::VESTING_TERMS;
+		invalid_terms.defi_instant_percentage = 101;
+		assert_err!(
+			AirdropModule::create_campaign(
+				RuntimeOrigin::root(),
+				merkle_root,
+				force_get_creditor_account::<Test>(),
+				invalid_terms,
+				0,
+				None,
+			),
+			PalletError::InvalidVestingTerms
+		);
+
+		// Campaign cannot end before it starts
+		assert_err!(
+			AirdropModule::create_campaign(
+				RuntimeOrigin::root(),
+				merkle_root,
+				force_get_creditor_account::<Test>(),
+				mock::VESTING_TERMS,
+				10,
+				Some(5),
+			),
+			PalletError::InvalidCampaignPeriod
+		);
+	});
+}
+
+#[test]
+fn closed_campaign_rejects_claim() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+
+		assert_ok!(AirdropModule::update_campaign_state(
+			RuntimeOrigin::root(),
+			0,
+			types::CampaignState::Closed
+		));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::CampaignStateUpdated {
+					campaign_id: 0,
+					old_state: types::CampaignState::Open,
+					new_state: types::CampaignState::Closed,
+				}
+				.into()
+			)
+		);
+
+		assert_err!(
+			claim_in(0, UserClaimTestCase::default()),
+			PalletError::CampaignClosed
+		);
+
+		// Re-opening campaign let claim pass again
+		assert_ok!(AirdropModule::update_campaign_state(
+			RuntimeOrigin::root(),
+			0,
+			types::CampaignState::Open
+		));
+		assert_ok!(claim_in(0, UserClaimTestCase::default()));
+	});
+}
+
+#[test]
+fn unknown_campaign() {
+	minimal_test_ext().execute_with(|| {
+		assert_err!(
+			claim_in(5, UserClaimTestCase::default()),
+			PalletError::NoSuchCampaign
+		);
+		assert_err!(
+			AirdropModule::update_campaign_state(
+				RuntimeOrigin::root(),
+				5,
+				types::CampaignState::Closed
+			),
+			PalletError::NoSuchCampaign
+		);
+		assert_err!(
+			AirdropModule::change_merkle_root(RuntimeOrigin::root(), 5, [0u8; 32]),
+			PalletError::NoSuchCampaign
+		);
+	});
+}
+
+#[test]
+fn claim_within_campaign_period() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		let campaign_id = create_campaign_with(case.merkle_root, 5, Some(10));
+
+		assert_err!(
+			claim_in(campaign_id, case.clone()),
+			PalletError::CampaignNotStarted
+		);
+
+		run_to_block(11);
+		assert_err!(
+			claim_in(campaign_id, case.clone()),
+			PalletError::CampaignEnded
+		);
+	});
+}
+
+#[test]
+fn same_icon_address_in_multiple_campaigns() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		let ice_account = AirdropModule::convert_to_account_id(case.ice_address).unwrap();
+
+		let second_campaign = create_campaign_with(case.merkle_root, 0, None);
+
+		assert_ok!(claim_in(0, case.clone()));
+		assert_err!(claim_in(0, case.clone()), PalletError::ClaimAlreadyMade);
+
+		// Being claimed in one round do not affect other round
+		assert_ok!(claim_in(second_campaign, case.clone()));
+		assert_err!(
+			claim_in(second_campaign, case.clone()),
+			PalletError::ClaimAlreadyMade
+		);
+
+		assert!(AirdropModule::get_icon_snapshot_map(0, &case.icon_address).is_some());
+		assert!(
+			AirdropModule::get_icon_snapshot_map(second_campaign, &case.icon_address).is_some()
+		);
+		assert_eq!(
+			<Test as Config>::Currency::total_balance(&ice_account),
+			case.amount * 2
+		);
+	});
+}
+
+#[test]
+fn change_merkle_root_of_campaign() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		let old_root = AirdropModule::get_merkle_root(0).unwrap();
+		let new_root = [2u8; 32];
+
+		assert_ok!(AirdropModule::change_merkle_root(
+			RuntimeOrigin::root(),
+			0,
+			new_root
+		));
+		assert_eq!(AirdropModule::get_merkle_root(0), Ok(new_root));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::MerkleRootUpdated {
+					campaign_id: 0,
+					old_root,
+					new_root,
+				}
+				.into()
+			)
+		);
+	});
+}
+
+#[test]
+fn set_vesting_terms() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		let new_terms = types::VestingTerms {
+			defi_instant_percentage: 100,
+			non_defi_instant_percentage: 50,
+			vesting_period: 100,
+		};
+
+		assert_ok!(AirdropModule::set_vesting_terms(
+			RuntimeOrigin::root(),
+			0,
+			new_terms
+		));
+		assert_eq!(
+			AirdropModule::get_campaign(0).unwrap().vesting_terms,
+			new_terms
+		);
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::VestingTermsUpdated {
+					campaign_id: 0,
+					old_terms: mock::VESTING_TERMS,
+					new_terms,
+				}
+				.into()
+			)
+		);
+
+		let mut invalid_terms = new_terms;
+		invalid_terms.non_defi_instant_percentage = 101;
+		assert_err!(
+			AirdropModule::set_vesting_terms(RuntimeOrigin::root(), 0, invalid_terms),
+			PalletError::InvalidVestingTerms
+		);
+		assert_err!(
+			AirdropModule::set_vesting_terms(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[0]),
+				0,
+				new_terms
+			),
+			PalletError::DeniedOperation
+		);
+		assert_err!(
+			AirdropModule::set_vesting_terms(RuntimeOrigin::root(), 5, new_terms),
+			PalletError::NoSuchCampaign
+		);
+	});
+}
+
+#[test]
+fn snapshot_record_applied_terms() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+
+		assert_ok!(claim_in(0, case.clone()));
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
+		assert_eq!(snapshot.vesting_terms, Some(mock::VESTING_TERMS));
+
+		// Later update do not rewrite history of past claims
+		let new_terms = types::VestingTerms {
+			defi_instant_percentage: 100,
+			non_defi_instant_percentage: 100,
+			vesting_period: 0,
+		};
+		assert_ok!(AirdropModule::set_vesting_terms(
+			RuntimeOrigin::root(),
+			0,
+			new_terms
+		));
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
+		assert_eq!(snapshot.vesting_terms, Some(mock::VESTING_TERMS));
+	});
+}
+
+#[test]
+fn set_claim_deadline() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			Some(5)
+		));
+		assert_eq!(AirdropModule::get_campaign(0).unwrap().end_block, Some(5));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::ClaimDeadlineUpdated {
+					campaign_id: 0,
+					old_deadline: None,
+					new_deadline: Some(5),
+				}
+				.into()
+			)
+		);
+
+		run_to_block(6);
+		assert_err!(
+			claim_in(0, UserClaimTestCase::default()),
+			PalletError::CampaignEnded
+		);
+
+		// Removing the deadline let claims pass again
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			None
+		));
+		assert_ok!(claim_in(0, UserClaimTestCase::default()));
+
+		assert_err!(
+			AirdropModule::set_claim_deadline(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[0]),
+				0,
+				Some(5)
+			),
+			PalletError::DeniedOperation
+		);
+		assert_err!(
+			AirdropModule::set_claim_deadline(RuntimeOrigin::root(), 5, Some(5)),
+			PalletError::NoSuchCampaign
+		);
+	});
+}
+
+#[test]
+fn sweep_unclaimed_funds() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		let creditor = force_get_creditor_account::<Test>();
+		let beneficiary = mock::UnclaimedFundsBeneficiary::get();
+
+		// Campaign without deadline can never be swept
+		assert_err!(
+			AirdropModule::sweep_unclaimed_funds(RuntimeOrigin::root(), 0),
+			PalletError::ClaimDeadlineNotReached
+		);
+
+		assert_ok!(claim_in(0, case.clone()));
+		assert_eq!(AirdropModule::get_distributed_amount(0), case.amount);
+
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			Some(10)
+		));
+		assert_err!(
+			AirdropModule::sweep_unclaimed_funds(RuntimeOrigin::root(), 0),
+			PalletError::ClaimDeadlineNotReached
+		);
+
+		run_to_block(11);
+		assert_err!(
+			AirdropModule::sweep_unclaimed_funds(RuntimeOrigin::signed(creditor), 0),
+			PalletError::DeniedOperation
+		);
+
+		let existential_deposit = <Test as Config>::Currency::minimum_balance();
+		let remaining = <Test as Config>::Currency::free_balance(&creditor) - existential_deposit;
+		assert_ok!(AirdropModule::sweep_unclaimed_funds(
+			RuntimeOrigin::root(),
+			0
+		));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::UnclaimedFundsSwept {
+					campaign_id: 0,
+					beneficiary,
+					distributed: case.amount,
+					swept: remaining,
+				}
+				.into()
+			)
+		);
+		// Creditor is kept alive
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&creditor),
+			existential_deposit
+		);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&beneficiary),
+			remaining
+		);
+		assert_eq!(
+			AirdropModule::get_campaign(0).unwrap().state,
+			types::CampaignState::Swept
+		);
+
+		// Swept campaign is final
+		assert_err!(
+			AirdropModule::sweep_unclaimed_funds(RuntimeOrigin::root(), 0),
+			PalletError::CampaignAlreadySwept
+		);
+		assert_err!(
+			AirdropModule::update_campaign_state(
+				RuntimeOrigin::root(),
+				0,
+				types::CampaignState::Open
+			),
+			PalletError::CampaignAlreadySwept
+		);
+		assert_err!(
+			AirdropModule::set_claim_deadline(RuntimeOrigin::root(), 0, None),
+			PalletError::CampaignAlreadySwept
+		);
+	});
+}
+
+#[test]
+fn campaigns_cannot_share_creditor() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let creditor = force_get_creditor_account::<Test>();
+		let second_campaign = create_campaign_with([1u8; 32], 0, Some(10));
+		let second_creditor = campaign_creditor(second_campaign);
+		let second_funds = <Test as Config>::Currency::free_balance(&second_creditor);
+
+		assert_eq!(AirdropModule::get_creditor_campaign(&creditor), Some(0));
+		assert_eq!(
+			AirdropModule::get_creditor_campaign(&second_creditor),
+			Some(second_campaign)
+		);
+
+		// Creditor of an unswept campaign cannot back another one
+		assert_err!(
+			AirdropModule::create_campaign(
+				RuntimeOrigin::root(),
+				[2u8; 32],
+				creditor,
+				mock::VESTING_TERMS,
+				0,
+				None,
+			),
+			PalletError::CreditorInUse
+		);
+		assert_err!(
+			AirdropModule::create_campaign(
+				RuntimeOrigin::root(),
+				[2u8; 32],
+				second_creditor,
+				mock::VESTING_TERMS,
+				0,
+				None,
+			),
+			PalletError::CreditorInUse
+		);
+
+		// Sweeping one campaign leave funds of other campaign untouched
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			Some(5)
+		));
+		run_to_block(6);
+		assert_ok!(AirdropModule::sweep_unclaimed_funds(
+			RuntimeOrigin::root(),
+			0
+		));
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&second_creditor),
+			second_funds
+		);
+		assert_eq!(
+			AirdropModule::get_campaign(second_campaign).unwrap().state,
+			types::CampaignState::Open
+		);
+
+		// Creditor of swept campaign can back a new one
+		assert_eq!(AirdropModule::get_creditor_campaign(&creditor), None);
+		let third_campaign = AirdropModule::get_next_campaign_id();
+		assert_ok!(AirdropModule::create_campaign(
+			RuntimeOrigin::root(),
+			[2u8; 32],
+			creditor,
+			mock::VESTING_TERMS,
+			0,
+			None,
+		));
+		assert_eq!(
+			AirdropModule::get_creditor_campaign(&creditor),
+			Some(third_campaign)
+		);
+	});
+}
+
+#[test]
+fn sweep_keeps_locked_funds() {
+	use frame_support::traits::{LockableCurrency, WithdrawReasons};
+
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let creditor = force_get_creditor_account::<Test>();
+		<Test as Config>::Currency::set_lock(*b"testlock", &creditor, 1000, WithdrawReasons::all());
+
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			Some(5)
+		));
+		run_to_block(6);
+		assert_err!(
+			AirdropModule::sweep_unclaimed_funds(RuntimeOrigin::root(), 0),
+			PalletError::CreditorFundsLocked
+		);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&creditor),
+			10_000_0000
+		);
+	});
+}
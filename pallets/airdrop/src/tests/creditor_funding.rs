@@ -0,0 +1,344 @@
+use super::prelude::*;
+use crate::tests::UserClaimTestCase;
+use frame_support::traits::{Currency, Get};
+
+type AirdropCurrency = <Test as pallet_airdrop::Config>::Currency;
+
+fn funding(
+	low_water_mark: types::BalanceOf<Test>,
+	refill_amount: types::BalanceOf<Test>,
+	allowance: types::BalanceOf<Test>,
+) -> types::CreditorFundingInfo<Test> {
+	types::CreditorFundingInfo {
+		low_water_mark,
+		refill_amount,
+		allowance,
+	}
+}
+
+fn low_balance_alerts() -> Vec<PalletEvent> {
+	get_pallet_events()
+		.into_iter()
+		.filter(|event| matches!(event, PalletEvent::CreditorBalanceLow { .. }))
+		.collect()
+}
+
+fn set_top_up_source_balance(balance: types::BalanceOf<Test>) {
+	AirdropCurrency::make_free_balance_be(&mock::CreditorTopUpSource::get(), balance);
+}
+
+#[test]
+fn set_creditor_funding() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		let new_funding = funding(10_000, 5_000, 8_000);
+
+		assert_noop!(
+			AirdropModule::set_creditor_funding(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[1]),
+				0,
+				Some(new_funding.clone()),
+			),
+			PalletError::DeniedOperation
+		);
+		assert_noop!(
+			AirdropModule::set_creditor_funding(
+				RuntimeOrigin::root(),
+				1,
+				Some(new_funding.clone())
+			),
+			PalletError::NoSuchCampaign
+		);
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(new_funding.clone()),
+		));
+		assert_eq!(
+			AirdropModule::get_creditor_funding(0),
+			Some(new_funding.clone())
+		);
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::CreditorFundingUpdated {
+					campaign_id: 0,
+					funding: Some(new_funding),
+				}
+				.into()
+			)
+		);
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			None
+		));
+		assert_eq!(AirdropModule::get_creditor_funding(0), None);
+	});
+}
+
+#[test]
+fn low_balance_alert_once_per_crossing() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(1_000);
+
+		// Without allowance creditor is only watched
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(funding(10_000, 5_000, 0)),
+		));
+
+		run_to_block(2);
+		assert_eq!(
+			low_balance_alerts(),
+			vec![PalletEvent::CreditorBalanceLow {
+				campaign_id: 0,
+				balance: 1_000,
+				low_water_mark: 10_000,
+			}]
+		);
+		assert!(AirdropModule::is_creditor_low(0));
+
+		// Still below the mark. No new alert
+		run_to_block(4);
+		assert_eq!(low_balance_alerts().len(), 1);
+
+		// Going back above the mark reset the alert
+		set_creditor_balance(20_000);
+		run_to_block(5);
+		assert!(!AirdropModule::is_creditor_low(0));
+		assert_eq!(low_balance_alerts().len(), 1);
+
+		// And crossing it again is alerted again
+		set_creditor_balance(2_000);
+		run_to_block(6);
+		assert_eq!(low_balance_alerts().len(), 2);
+		assert!(AirdropModule::is_creditor_low(0));
+	});
+}
+
+#[test]
+fn refill_within_allowance() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(1_000);
+		set_top_up_source_balance(100_000);
+		let creditor = force_get_creditor_account::<Test>();
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(funding(10_000, 5_000, 8_000)),
+		));
+
+		// Refilled but still below the mark
+		run_to_block(2);
+		assert_eq!(AirdropCurrency::free_balance(&creditor), 6_000);
+		assert_eq!(
+			AirdropModule::get_creditor_funding(0).unwrap().allowance,
+			3_000
+		);
+		assert!(
+			get_pallet_events().contains(&PalletEvent::CreditorToppedUp {
+				campaign_id: 0,
+				amount: 5_000,
+				remaining_allowance: 3_000,
+			})
+		);
+		assert_eq!(low_balance_alerts().len(), 1);
+
+		// Only what is left in allowance is moved
+		run_to_block(3);
+		assert_eq!(AirdropCurrency::free_balance(&creditor), 9_000);
+		assert_eq!(AirdropModule::get_creditor_funding(0).unwrap().allowance, 0);
+
+		// Allowance is exhausted
+		run_to_block(4);
+		assert_eq!(AirdropCurrency::free_balance(&creditor), 9_000);
+		assert_eq!(
+			AirdropCurrency::free_balance(&mock::CreditorTopUpSource::get()),
+			100_000 - 8_000
+		);
+		assert_eq!(low_balance_alerts().len(), 1);
+	});
+}
+
+#[test]
+fn no_refill_for_closed_campaign() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(1_000);
+		set_top_up_source_balance(100_000);
+		let creditor = force_get_creditor_account::<Test>();
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(funding(10_000, 5_000, 8_000)),
+		));
+		assert_ok!(AirdropModule::update_campaign_state(
+			RuntimeOrigin::root(),
+			0,
+			types::CampaignState::Closed,
+		));
+
+		run_to_block(3);
+		assert_eq!(AirdropCurrency::free_balance(&creditor), 1_000);
+		assert_eq!(
+			AirdropModule::get_creditor_funding(0).unwrap().allowance,
+			8_000
+		);
+	});
+}
+
+#[test]
+fn funded_campaigns_are_bounded() {
+	use frame_support::traits::Hooks;
+
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		for creditor in [[0xd1u8; 32], [0xd2u8; 32]] {
+			assert_ok!(AirdropModule::create_campaign(
+				RuntimeOrigin::root(),
+				[1u8; 32],
+				types::AccountIdOf::<Test>::from_raw(creditor),
+				mock::VESTING_TERMS,
+				0,
+				None,
+			));
+		}
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(funding(10_000, 5_000, 0)),
+		));
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			1,
+			Some(funding(10_000, 5_000, 0)),
+		));
+		// Updating funding of already funded campaign take no new slot
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			1,
+			Some(funding(20_000, 5_000, 0)),
+		));
+		assert_eq!(AirdropModule::get_funded_campaigns().to_vec(), vec![0, 1]);
+
+		let max_funded: u32 = <Test as pallet_airdrop::Config>::MaxFundedCampaigns::get();
+		assert_eq!(max_funded, 2);
+		assert_noop!(
+			AirdropModule::set_creditor_funding(
+				RuntimeOrigin::root(),
+				2,
+				Some(funding(10_000, 5_000, 0)),
+			),
+			PalletError::TooManyFundedCampaigns
+		);
+
+		// Removing funding free the slot
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			None
+		));
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			2,
+			Some(funding(10_000, 5_000, 0)),
+		));
+		assert_eq!(AirdropModule::get_funded_campaigns().to_vec(), vec![1, 2]);
+
+		// Closed campaign cost a single read on top of reading the list
+		assert_ok!(AirdropModule::update_campaign_state(
+			RuntimeOrigin::root(),
+			2,
+			types::CampaignState::Closed,
+		));
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		assert_eq!(AirdropModule::refill_creditor(2), db_weight.reads(1));
+		// Only funded campaigns are checked while initializing a block
+		assert_eq!(
+			AirdropModule::on_initialize(2),
+			db_weight.reads(1) + AirdropModule::refill_creditor(1) + db_weight.reads(1)
+		);
+	});
+}
+
+#[test]
+fn sweep_removes_creditor_funding() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(1_000);
+		set_top_up_source_balance(100_000);
+		let creditor = force_get_creditor_account::<Test>();
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(funding(10_000, 5_000, 8_000)),
+		));
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			Some(1)
+		));
+		run_to_block(2);
+		assert_ok!(AirdropModule::sweep_unclaimed_funds(
+			RuntimeOrigin::root(),
+			0
+		));
+		assert_eq!(AirdropModule::get_creditor_funding(0), None);
+		assert!(AirdropModule::get_funded_campaigns().is_empty());
+		assert!(!AirdropModule::is_creditor_low(0));
+
+		// Swept creditor is never refilled again
+		let balance = AirdropCurrency::free_balance(&creditor);
+		run_to_block(4);
+		assert_eq!(AirdropCurrency::free_balance(&creditor), balance);
+	});
+}
+
+#[test]
+fn claim_crossing_low_water_mark() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(20_000_000);
+
+		assert_ok!(AirdropModule::set_creditor_funding(
+			RuntimeOrigin::root(),
+			0,
+			Some(funding(10_000_000, 0, 0)),
+		));
+
+		let case = UserClaimTestCase::default();
+		assert_ok!(AirdropModule::dispatch_user_claim(
+			RuntimeOrigin::root(),
+			0,
+			case.icon_address,
+			case.ice_address,
+			case.message,
+			case.icon_signature,
+			case.ice_signature,
+			case.amount,
+			case.defi_user,
+			case.merkle_proofs,
+			case.expiry,
+		));
+
+		let creditor = force_get_creditor_account::<Test>();
+		assert_eq!(
+			low_balance_alerts(),
+			vec![PalletEvent::CreditorBalanceLow {
+				campaign_id: 0,
+				balance: AirdropCurrency::free_balance(&creditor),
+				low_water_mark: 10_000_000,
+			}]
+		);
+	});
+}
@@ -0,0 +1,150 @@
+use super::prelude::*;
+use crate::Config;
+use frame_support::traits::Currency;
+
+const ICON_ADDRESS: types::IconAddress = decode_hex!("396031be52ec56955bd7bf15eacdfa1a1c1fe19e");
+const EVM_ADDRESS: [u8; 20] = decode_hex!("1563915e194d8cfba1943570603f7606a3115508");
+// HashedAddressMapping<BlakeTwo256> of EVM_ADDRESS
+const MAPPED_ACCOUNT: [u8; 32] =
+	decode_hex!("e4bebcc659edfd2570352f63c097fa0b2b4c6a04ec5137e9b77fd87ef353df48");
+const MESSAGE: &[u8] = b"icx_sendTransaction.data.{method.transfer.params.{wallet.1563915e194d8cfba1943570603f7606a3115508}}.dataType.call.from.hx396031be52ec56955bd7bf15eacdfa1a1c1fe19e.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hx396031be52ec56955bd7bf15eacdfa1a1c1fe19e.version.0x3";
+const ICON_SIGNATURE: types::IconSignature = decode_hex!("ec6d499aefd540e90357f1004a136049d1f7df5ad99c44c46e3ed4169e40acb67a7ce82cede20e9d916f2eb0fe80fb3406d40d0d89747d744263921f1fd1193200");
+const EVM_SIGNATURE: types::IceEvmSignature = decode_hex!("71550e6c83a9381f35c568d1a80e11fa3e0efc97dfd0e0f17492a2edb64c37a94ed3692250f390c4c0446251d4f15d549846583cee73beaacb10f1aa9046e54901");
+const AMOUNT: types::BalanceOf<Test> = 12_000_000;
+const EXPIRY: types::BlockNumberOf<Test> = 1_000;
+
+fn evm_claim(
+	icon_address: types::IconAddress,
+	evm_address: [u8; 20],
+	evm_signature: types::IceEvmSignature,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	AirdropModule::dispatch_evm_user_claim(
+		RuntimeOrigin::root(),
+		0,
+		icon_address,
+		evm_address.into(),
+		to_payload(MESSAGE),
+		ICON_SIGNATURE,
+		evm_signature,
+		AMOUNT,
+		true,
+		Default::default(),
+		EXPIRY,
+	)
+}
+
+#[test]
+fn mapped_account() {
+	assert_eq!(
+		utils::into_account_id(EVM_ADDRESS.into()),
+		MAPPED_ACCOUNT.into()
+	);
+}
+
+#[test]
+fn recover_evm_address() {
+	assert_eq!(
+		utils::recover_evm_address(&EVM_SIGNATURE, &ICON_SIGNATURE),
+		Some(EVM_ADDRESS.into())
+	);
+
+	// Signature of different message recover into different address
+	assert_ne!(
+		utils::recover_evm_address(&EVM_SIGNATURE, MESSAGE),
+		Some(EVM_ADDRESS.into())
+	);
+}
+
+#[test]
+fn evm_claim_success() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let ice_account = types::AccountIdOf::<Test>::from_raw(MAPPED_ACCOUNT);
+
+		assert_ok!(evm_claim(ICON_ADDRESS, EVM_ADDRESS, EVM_SIGNATURE));
+		match get_pallet_events().pop() {
+			Some(PalletEvent::ClaimSuccess {
+				campaign_id,
+				icon_address,
+				claimer,
+				instant_amount,
+				vested_amount,
+				block_number,
+				skipped_phase,
+				..
+			}) => {
+				assert_eq!(campaign_id, 0);
+				assert_eq!(icon_address, ICON_ADDRESS);
+				assert_eq!(claimer, ice_account);
+				assert_eq!(instant_amount + vested_amount, AMOUNT);
+				assert_eq!(block_number, 1);
+				assert_eq!(skipped_phase, None);
+			}
+			other => panic!("Expected ClaimSuccess event. Got: {other:?}"),
+		}
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, ICON_ADDRESS).unwrap();
+		assert_eq!(snapshot.ice_address, ice_account);
+		assert!(snapshot.done_instant && snapshot.done_vesting);
+		assert_eq!(
+			AirdropModule::get_ice_to_icon_map(&ice_account),
+			Some(ICON_ADDRESS)
+		);
+		assert_eq!(
+			<Test as Config>::Currency::total_balance(&ice_account),
+			AMOUNT
+		);
+
+		assert_err!(
+			evm_claim(ICON_ADDRESS, EVM_ADDRESS, EVM_SIGNATURE),
+			PalletError::ClaimAlreadyMade
+		);
+	});
+}
+
+#[test]
+fn invalid_evm_signature() {
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+
+		let mut evm_signature = EVM_SIGNATURE;
+		evm_signature[10] ^= 1;
+		assert_err!(
+			evm_claim(ICON_ADDRESS, EVM_ADDRESS, evm_signature),
+			PalletError::InvalidEvmSignature
+		);
+
+		// Invalid recovery id
+		let mut evm_signature = EVM_SIGNATURE;
+		evm_signature[64] = 5;
+		assert_err!(
+			evm_claim(ICON_ADDRESS, EVM_ADDRESS, evm_signature),
+			PalletError::InvalidEvmSignature
+		);
+	});
+}
+
+#[test]
+fn evm_address_not_in_message() {
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+
+		assert_err!(
+			evm_claim(ICON_ADDRESS, [1u8; 20], EVM_SIGNATURE),
+			PalletError::InvalidMessagePayload
+		);
+	});
+}
+
+#[test]
+fn invalid_icon_signer() {
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+
+		assert_err!(
+			evm_claim(samples::VALID_ICON_WALLET, EVM_ADDRESS, EVM_SIGNATURE),
+			PalletError::InvalidSignature
+		);
+	});
+}
@@ -26,6 +26,7 @@ fn claim_success() {
 
 		assert_ok!(AirdropModule::dispatch_exchange_claim(
 			RuntimeOrigin::root(),
+			0,
 			icon_wallet,
 			ice_address.encode().try_into().unwrap(),
 			amount.into(),
@@ -33,7 +34,7 @@ fn claim_success() {
 			bounded_proofs,
 		));
 
-		let snapshot = AirdropModule::get_icon_snapshot_map(&icon_wallet).unwrap();
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &icon_wallet).unwrap();
 		let expected_vesting_block_number = if cfg!(feature = "no-vesting") {
 			None
 		} else {
@@ -78,6 +79,7 @@ fn insufficient_balance() {
 		assert_err!(
 			AirdropModule::dispatch_exchange_claim(
 				RuntimeOrigin::root(),
+				0,
 				icon_wallet,
 				ice_address.clone(),
 				amount,
@@ -105,6 +107,7 @@ fn double_exchange() {
 
 		AirdropModule::dispatch_exchange_claim(
 			RuntimeOrigin::root(),
+			0,
 			icon_wallet,
 			ice_address.clone(),
 			amount,
@@ -120,8 +123,8 @@ fn double_exchange() {
 		// First exchange should pass
 		assert_ok!(first_exchange);
 
-		// Second exchange should fail
-		assert_err!(second_exchange, PalletError::ClaimAlreadyMade);
+		// Second exchange should fail as first one settled the account
+		assert_err!(second_exchange, PalletError::ExchangeClaimSettled);
 	});
 }
 
@@ -145,12 +148,13 @@ fn already_claimed() {
 		snapshot.done_instant = true;
 		snapshot.done_vesting = true;
 
-		pallet_airdrop::IconSnapshotMap::<Test>::insert(&icon_wallet, snapshot);
+		pallet_airdrop::IconSnapshotMap::<Test>::insert(0, &icon_wallet, snapshot);
 		pallet_airdrop::ExchangeAccountsMap::<Test>::insert(&icon_wallet, amount);
 
 		assert_err!(
 			AirdropModule::dispatch_exchange_claim(
 				RuntimeOrigin::root(),
+				0,
 				icon_wallet,
 				ice_address.clone(),
 				amount,
@@ -177,7 +181,7 @@ fn only_whitelisted_claim() {
 
 		let snapshot = types::SnapshotInfo::default();
 
-		pallet_airdrop::IconSnapshotMap::<Test>::insert(&icon_wallet, snapshot);
+		pallet_airdrop::IconSnapshotMap::<Test>::insert(0, &icon_wallet, snapshot);
 		let creditor_account = force_get_creditor_account::<Test>();
 		<Test as pallet_airdrop::Config>::Currency::set_balance(
 			mock::RuntimeOrigin::root(),
@@ -190,6 +194,7 @@ fn only_whitelisted_claim() {
 		assert_err!(
 			AirdropModule::dispatch_exchange_claim(
 				RuntimeOrigin::root(),
+				0,
 				icon_wallet,
 				ice_address.clone(),
 				amount,
@@ -218,7 +223,7 @@ fn invalid_claim_amount() {
 		snapshot.done_instant = true;
 		snapshot.done_vesting = true;
 
-		pallet_airdrop::IconSnapshotMap::<Test>::insert(&icon_wallet, snapshot);
+		pallet_airdrop::IconSnapshotMap::<Test>::insert(0, &icon_wallet, snapshot);
 		let creditor_account = force_get_creditor_account::<Test>();
 		pallet_airdrop::ExchangeAccountsMap::<Test>::insert(&icon_wallet, amount);
 		<Test as pallet_airdrop::Config>::Currency::set_balance(
@@ -232,6 +237,7 @@ fn invalid_claim_amount() {
 		assert_err!(
 			AirdropModule::dispatch_exchange_claim(
 				RuntimeOrigin::root(),
+				0,
 				icon_wallet,
 				ice_address.clone(),
 				amount + 10000,
@@ -242,3 +248,149 @@ fn invalid_claim_amount() {
 		);
 	});
 }
+
+#[test]
+fn settle_exchange_account() {
+	let case = to_test_case(samples::MERKLE_PROOF_SAMPLE);
+	let bounded_proofs = BoundedVec::<types::MerkleHash, ConstU32<10>>::try_from(case.1).unwrap();
+	let amount: types::BalanceOf<Test> = 10017332_u64.into();
+
+	minimal_test_ext().execute_with(|| {
+		run_to_block(2);
+		set_creditor_balance(10_000_0000);
+		let icon_wallet = VALID_ICON_WALLET;
+		let ice_address =
+			hex_literal::hex!("da8db20713c087e12abae13f522693299b9de1b70ff0464caa5d392396a8f76c");
+
+		assert_ok!(AirdropModule::add_exchange_account(
+			RuntimeOrigin::root(),
+			icon_wallet,
+			amount
+		));
+		assert_eq!(
+			AirdropModule::pending_exchange_claims()
+				.into_iter()
+				.find(|claim| claim.icon_address == icon_wallet.into())
+				.map(|claim| claim.amount),
+			Some(amount)
+		);
+
+		assert_ok!(AirdropModule::dispatch_exchange_claim(
+			RuntimeOrigin::root(),
+			0,
+			icon_wallet,
+			ice_address,
+			amount,
+			true,
+			bounded_proofs,
+		));
+
+		// Settled account is no longer pending and can't be added back
+		assert_eq!(AirdropModule::get_exchange_settlement(icon_wallet), Some(2));
+		assert_eq!(AirdropModule::get_exchange_account(icon_wallet), None);
+		assert!(AirdropModule::pending_exchange_claims()
+			.iter()
+			.all(|claim| claim.icon_address != icon_wallet.into()));
+		assert_err!(
+			AirdropModule::add_exchange_account(RuntimeOrigin::root(), icon_wallet, amount),
+			PalletError::ExchangeClaimSettled
+		);
+	});
+}
+
+#[test]
+fn manage_exchange_accounts() {
+	let case = to_test_case(samples::MERKLE_PROOF_SAMPLE);
+	let bounded_proofs = BoundedVec::<types::MerkleHash, ConstU32<10>>::try_from(case.1).unwrap();
+
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let icon_wallet = VALID_ICON_WALLET;
+		let ice_address =
+			hex_literal::hex!("da8db20713c087e12abae13f522693299b9de1b70ff0464caa5d392396a8f76c");
+
+		assert_ok!(AirdropModule::add_exchange_account(
+			RuntimeOrigin::root(),
+			icon_wallet,
+			100
+		));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::ExchangeAccountAdded {
+					icon_address: icon_wallet,
+					amount: 100,
+				}
+				.into()
+			)
+		);
+		assert_err!(
+			AirdropModule::add_exchange_account(RuntimeOrigin::root(), icon_wallet, 100),
+			PalletError::ExchangeAccountExists
+		);
+
+		assert_ok!(AirdropModule::update_exchange_account(
+			RuntimeOrigin::root(),
+			icon_wallet,
+			200
+		));
+		assert_eq!(AirdropModule::get_exchange_account(icon_wallet), Some(200));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::ExchangeAccountUpdated {
+					icon_address: icon_wallet,
+					old_amount: 100,
+					new_amount: 200,
+				}
+				.into()
+			)
+		);
+
+		assert_ok!(AirdropModule::remove_exchange_account(
+			RuntimeOrigin::root(),
+			icon_wallet
+		));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::ExchangeAccountRemoved {
+					icon_address: icon_wallet,
+					amount: 200,
+				}
+				.into()
+			)
+		);
+
+		// Removed account can't claim anymore
+		assert_err!(
+			AirdropModule::dispatch_exchange_claim(
+				RuntimeOrigin::root(),
+				0,
+				icon_wallet,
+				ice_address,
+				200,
+				true,
+				bounded_proofs,
+			),
+			PalletError::DeniedOperation
+		);
+		assert_err!(
+			AirdropModule::update_exchange_account(RuntimeOrigin::root(), icon_wallet, 100),
+			PalletError::NoSuchExchangeAccount
+		);
+		assert_err!(
+			AirdropModule::remove_exchange_account(RuntimeOrigin::root(), icon_wallet),
+			PalletError::NoSuchExchangeAccount
+		);
+		assert_err!(
+			AirdropModule::add_exchange_account(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[0]),
+				icon_wallet,
+				100
+			),
+			PalletError::DeniedOperation
+		);
+	});
+}
@@ -0,0 +1,193 @@
+use super::prelude::*;
+use frame_support::traits::ConstU64;
+use pallet_airdrop::icon::{parse_transaction, IconPayload, IconVerifier, MAX_PAYLOAD_LENGTH};
+use types::{SignatureValidationError, SourceChainVerifier};
+
+type MainnetVerifier = IconVerifier<ConstU64<1>>;
+
+// Signed by icon address ICON_SIGNER with timestamp longer than the sample
+const ICON_SIGNER: types::IconAddress = decode_hex!("eae38983e1a9da83663d539053ee5fef1c41a028");
+const LONG_MESSAGE: &[u8] = b"icx_sendTransaction.data.{method.transfer.params.{wallet.0xb6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxeae38983e1a9da83663d539053ee5fef1c41a028.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x5f5e0ff3b7a1c.to.hxeae38983e1a9da83663d539053ee5fef1c41a028.version.0x3";
+const LONG_MESSAGE_SIGNATURE: types::IconSignature = decode_hex!("bd5bfe127d4788041265a99567297683469457e313609d8970e5f49a9332bd0711921228f7c9d183bc9e280e96f2aa3ff18efb52f7f4e90a4c3be10df8f5469000");
+
+// Signed by ICON_SIGNER while claiming to be sent from sample icon wallet
+const FORGED_MESSAGE: &[u8] = b"icx_sendTransaction.data.{method.transfer.params.{wallet.0xb6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x5f5e0ff3b7a1c.to.hxeae38983e1a9da83663d539053ee5fef1c41a028.version.0x3";
+const FORGED_MESSAGE_SIGNATURE: types::IconSignature = decode_hex!("b420a2b87a1298fd33e3cab84403974f5eb0b82b06342e43c2b14c37d69e29c02afd6049ae387c3fbae151aa48ef16d4c9d65ba35bf9c565e9ab056de0b9a12201");
+
+/// Build `icx_sendTransaction` message of transfer call with given extra
+/// top level fields put before `from`
+fn transfer_message(extra: &str) -> Vec<u8> {
+	format!(
+		"icx_sendTransaction.data.{{method.transfer.params.{{wallet.{}}}}}.dataType.call.{extra}from.hx{}.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hx{}.version.0x3",
+		hex::encode(samples::VALID_ICE_ADDRESS),
+		hex::encode(samples::VALID_ICON_WALLET),
+		hex::encode(samples::VALID_ICON_WALLET),
+	)
+	.into_bytes()
+}
+
+#[test]
+fn parse_sample_transaction() {
+	let transaction = parse_transaction(samples::VALID_MESSAGE).unwrap();
+
+	assert_eq!(transaction.from, samples::VALID_ICON_WALLET);
+	assert_eq!(
+		transaction.to,
+		b"hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645".to_vec()
+	);
+	assert_eq!(transaction.nid, 1);
+	assert_eq!(transaction.timestamp, 0);
+	assert_eq!(
+		transaction.wallet,
+		hex::encode(samples::VALID_ICE_ADDRESS).into_bytes()
+	);
+}
+
+#[test]
+fn parse_variable_length_transaction() {
+	let transaction = parse_transaction(LONG_MESSAGE).unwrap();
+	assert_eq!(transaction.from, ICON_SIGNER);
+	assert_eq!(transaction.timestamp, 0x5f5e0ff3b7a1c);
+
+	// Destination with 0x prefix
+	assert_eq!(
+		MainnetVerifier::extract_destination(LONG_MESSAGE, 32),
+		Some(samples::VALID_ICE_ADDRESS.to_vec())
+	);
+	assert_eq!(
+		MainnetVerifier::recover_signer(&LONG_MESSAGE_SIGNATURE, LONG_MESSAGE),
+		Ok(ICON_SIGNER)
+	);
+
+	// Unknown fields, nested values and escaped chars are skipped over
+	let message = transfer_message(r"memo.hello\.world\{\}.stepList.[0x1.{a.b}.[]].empty.{}.");
+	let transaction = parse_transaction(&message).unwrap();
+	assert_eq!(transaction.from, samples::VALID_ICON_WALLET);
+	assert_eq!(
+		MainnetVerifier::extract_destination(&message, 32),
+		Some(samples::VALID_ICE_ADDRESS.to_vec())
+	);
+}
+
+#[test]
+fn reject_malformed_transaction() {
+	let valid = samples::VALID_MESSAGE;
+	let replace = |from: &str, to: &str| {
+		let message = core::str::from_utf8(valid).unwrap().replacen(from, to, 1);
+		assert_ne!(message.as_bytes(), valid);
+		message.into_bytes()
+	};
+
+	// Not a send transaction
+	assert!(parse_transaction(&valid[1..]).is_none());
+	// Trailing garbage
+	assert!(parse_transaction(&[valid, b"}"].concat()).is_none());
+	assert!(parse_transaction(&[valid, b".extra"].concat()).is_none());
+	// Unbalanced braces
+	assert!(parse_transaction(&replace("}}.dataType", "}.dataType")).is_none());
+	// Not a transfer call
+	assert!(parse_transaction(&replace("method.transfer", "method.approve")).is_none());
+	assert!(parse_transaction(&replace("dataType.call", "dataType.message")).is_none());
+	// Bad numbers and address
+	assert!(parse_transaction(&replace("nid.0x1", "nid.1")).is_none());
+	assert!(parse_transaction(&replace("timestamp.0x0", "timestamp.0xz")).is_none());
+	assert!(parse_transaction(&replace("from.hx", "from.cx")).is_none());
+	// Dangling escape
+	assert!(parse_transaction(&[valid, b".memo.\\"].concat()).is_none());
+
+	// Nesting deeper than parser allows
+	let message = transfer_message("deep.{a.{b.{c.{d.{e.f}}}}}.");
+	assert!(parse_transaction(&message).is_none());
+	let message = transfer_message("deep.{a.{b.{c.{d.e}}}}.");
+	assert!(parse_transaction(&message).is_some());
+}
+
+#[test]
+fn reject_wrong_destination() {
+	// Destination of different length
+	assert_eq!(
+		MainnetVerifier::extract_destination(samples::VALID_MESSAGE, 20),
+		None
+	);
+
+	let message = core::str::from_utf8(samples::VALID_MESSAGE)
+		.unwrap()
+		.replacen("wallet.b6", "wallet.x6", 1);
+	assert_eq!(
+		MainnetVerifier::extract_destination(message.as_bytes(), 32),
+		None
+	);
+}
+
+#[test]
+fn signed_at_from_transaction_timestamp() {
+	assert_eq!(MainnetVerifier::signed_at(samples::VALID_MESSAGE), Some(0));
+
+	// Timestamp 0x5f5e0ff3b7a1c is in microseconds
+	assert_eq!(
+		MainnetVerifier::signed_at(LONG_MESSAGE),
+		Some(1_677_721_587_120)
+	);
+
+	assert_eq!(MainnetVerifier::signed_at(b"icx_sendTransaction."), None);
+}
+
+#[test]
+fn recover_signer_checks_transaction() {
+	// Message signed for mainnet is not valid in other network
+	assert_eq!(
+		IconVerifier::<ConstU64<2>>::recover_signer(
+			&samples::VALID_ICON_SIGNATURE,
+			samples::VALID_MESSAGE
+		),
+		Err(SignatureValidationError::NetworkMismatch)
+	);
+
+	// Signer must be the sender written in message
+	assert_eq!(
+		MainnetVerifier::recover_signer(&FORGED_MESSAGE_SIGNATURE, FORGED_MESSAGE),
+		Err(SignatureValidationError::SenderMismatch)
+	);
+
+	assert_eq!(
+		MainnetVerifier::recover_signer(
+			&samples::VALID_ICON_SIGNATURE,
+			&samples::VALID_MESSAGE[1..]
+		),
+		Err(SignatureValidationError::MalformedPayload)
+	);
+}
+
+#[test]
+fn payload_length_is_bounded() {
+	let max_length = MAX_PAYLOAD_LENGTH as usize;
+	assert!(IconPayload::try_from(vec![0; max_length]).is_ok());
+	assert!(IconPayload::try_from(vec![0; max_length + 1]).is_err());
+}
+
+#[test]
+fn claim_rejected_on_network_mismatch() {
+	minimal_test_ext().execute_with(|| {
+		let case = tests::UserClaimTestCase::default();
+		let message = core::str::from_utf8(samples::VALID_MESSAGE)
+			.unwrap()
+			.replacen("nid.0x1", "nid.0x2", 1);
+
+		assert_noop!(
+			AirdropModule::dispatch_user_claim(
+				RuntimeOrigin::root(),
+				0,
+				case.icon_address,
+				case.ice_address,
+				to_payload(message.as_bytes()),
+				case.icon_signature,
+				case.ice_signature,
+				case.amount,
+				case.defi_user,
+				case.merkle_proofs,
+				case.expiry,
+			),
+			PalletError::InvalidNetworkId
+		);
+	});
+}
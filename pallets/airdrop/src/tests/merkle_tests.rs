@@ -1,4 +1,8 @@
-use crate::merkle::{hash_leaf, proof_root, sort_array};
+use crate::merkle::{
+	hash_leaf, proof_root, sort_array, AbiLeafEncoder, Blake2MerkleHasher, IconLeafEncoder,
+	KeccakMerkleHasher,
+};
+use crate::types::{MerkleLeafEncoder, MerkleNodeHasher};
 use crate::utils;
 use hex_literal;
 
@@ -6,7 +10,9 @@ use hex_literal;
 fn test_hash_leaf() {
 	let expected = "7fe522d63ebcabfa052eec3647366138c23c9870995f4af94d9b22b8c5923f49";
 	let icon_addr: [u8; 20] = hex_literal::hex!("a99344ea068864f8af6cbcf89328d6eb3d7e8c9c");
-	let result = hex::encode(hash_leaf(&icon_addr, 0, true));
+	let result = hex::encode(hash_leaf::<IconLeafEncoder, Blake2MerkleHasher>(
+		&icon_addr, 0, true,
+	));
 	assert_eq!(expected, &result);
 }
 
@@ -151,10 +157,120 @@ fn fails_invalid_proof() {
 		bytes
 	})
 	.collect::<Vec<[u8; 32]>>();
-	let proof_root = proof_root(leaf_hash, proofs);
+	let proof_root = proof_root::<Blake2MerkleHasher>(leaf_hash, proofs);
 	assert_ne!(root, hex::encode(proof_root));
 }
 
+#[test]
+fn test_node_hashers() {
+	assert_eq!(
+		hex::encode(Blake2MerkleHasher::hash(b"")),
+		"0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+	);
+	assert_eq!(
+		hex::encode(KeccakMerkleHasher::hash(b"")),
+		"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+	);
+}
+
+#[test]
+fn test_abi_leaf_encoder() {
+	let icon_addr: [u8; 20] = hex_literal::hex!("a99344ea068864f8af6cbcf89328d6eb3d7e8c9c");
+	let expected = [
+		"000000000000000000000000a99344ea068864f8af6cbcf89328d6eb3d7e8c9c",
+		"0000000000000000000000000000000000000000000000000de0b6b3a7640000",
+		"0000000000000000000000000000000000000000000000000000000000000001",
+	]
+	.concat();
+	let result = AbiLeafEncoder::encode_leaf(&icon_addr, 10_u128.pow(18), true);
+	assert_eq!(expected, hex::encode(result));
+}
+
+#[test]
+fn test_verify_proof_keccak_abi() {
+	let root = "fdb59eaa0d1ccfd5a7f34dd4b283dcd87ffdb530ccb9f8a01d07abd0fd857deb";
+	let icon_addr: [u8; 20] = hex_literal::hex!("a99344ea068864f8af6cbcf89328d6eb3d7e8c9c");
+	let leaf = hash_leaf::<AbiLeafEncoder, KeccakMerkleHasher>(&icon_addr, 0, true);
+	assert_eq!(
+		hex::encode(leaf),
+		"fc4720d988bd761792c8e0f7cc231d606ecc734b029d7b28ef956b0798c05e58"
+	);
+
+	let cases = [
+		(
+			"fc4720d988bd761792c8e0f7cc231d606ecc734b029d7b28ef956b0798c05e58",
+			[
+				"8bbe333ab5f51a18bf7bcdf3c0226155e08577318c99c60a27da421858a18476",
+				"f7cef33df01070eda43d43b959675c0cb3ed7ef77bcb85a44b2cec4862c75640",
+			],
+		),
+		(
+			"0c4326c487d23eb389e4e8f1d335e70315f51ca1f630ac5478b9124347a0588e",
+			[
+				"74be6b93a769ed8743b4bdbfdfe1dc81c5a371069f44870c4c95e5e2cc6ca4df",
+				"6e6cf864c8a4ecd35640cf975cc9b036e49dca56663b18e30659ec33fda4efd6",
+			],
+		),
+	];
+	for case in cases {
+		verify_proof_case_with::<KeccakMerkleHasher>(root, case.0, case.1.to_vec());
+	}
+
+	// Same tree do not verify with other hasher
+	let leaf_hash = utils::hex_as_byte_array(cases[0].0).unwrap();
+	let proofs = cases[0]
+		.1
+		.iter()
+		.map(|h| utils::hex_as_byte_array(h).unwrap())
+		.collect::<Vec<[u8; 32]>>();
+	assert_ne!(
+		root,
+		hex::encode(proof_root::<Blake2MerkleHasher>(leaf_hash, proofs))
+	);
+}
+
+#[test]
+fn test_verify_proof_blake2_abi() {
+	let root = "ef21f51cd21d64b767c5f6774d302adf372b30df0d81ee7ab5110ed07c339e58";
+	let icon_addr: [u8; 20] = hex_literal::hex!("3d16047c23cc3e27e807f6cfc55fb8d950555690");
+	let leaf = hash_leaf::<AbiLeafEncoder, Blake2MerkleHasher>(&icon_addr, 12_000_000, false);
+	assert_eq!(
+		hex::encode(leaf),
+		"54b74e82131ea0f06d41fbf561eba6d1cbc100918ae6f60ffab14e8c06ee6ac7"
+	);
+
+	verify_proof_case_with::<Blake2MerkleHasher>(
+		root,
+		"54b74e82131ea0f06d41fbf561eba6d1cbc100918ae6f60ffab14e8c06ee6ac7",
+		[
+			"12a7a5fa943c8e1469271da48ca1b6681f4d8e4dcc553090b70fd4edd02c4073",
+			"1c7e03190ee492ba4ac0e152f53a5b757a37e9e45e0884d83e40a6f7ef7c4458",
+		]
+		.to_vec(),
+	);
+}
+
+#[test]
+fn test_verify_proof_keccak_icon() {
+	let root = "2840956e467b3618b208c7f90ad0cbe99ed0efe167ffbae28a705d0c9d5d1c10";
+	let icon_addr: [u8; 20] = hex_literal::hex!("396031be52ec56955bd7bf15eacdfa1a1c1fe19e");
+	let leaf = hash_leaf::<IconLeafEncoder, KeccakMerkleHasher>(&icon_addr, u128::MAX, false);
+	assert_eq!(
+		hex::encode(leaf),
+		"244ed641fe689324227920388243aca0bf09be2970623e8d78161db89b7ba298"
+	);
+
+	verify_proof_case_with::<KeccakMerkleHasher>(
+		root,
+		"244ed641fe689324227920388243aca0bf09be2970623e8d78161db89b7ba298",
+		[
+			"9b69f94df6cdbf0aefc95cca07a8eb9b35e15af88bac63748f35af90f0619ef8",
+			"a27cdced88ad0db53c7bed4eed559a745af8cabbf365d641c510c4a5ca44c1c9",
+		]
+		.to_vec(),
+	);
+}
+
 #[test]
 fn test_sort_array() {
 	let arr1 = [0u8; 32];
@@ -174,6 +290,10 @@ fn test_sort_array() {
 }
 
 pub fn verify_proof_case(root: &str, leaf: &str, proofs: Vec<&str>) {
+	verify_proof_case_with::<Blake2MerkleHasher>(root, leaf, proofs)
+}
+
+pub fn verify_proof_case_with<H: MerkleNodeHasher>(root: &str, leaf: &str, proofs: Vec<&str>) {
 	let leaf_hash = utils::hex_as_byte_array(leaf).unwrap();
 
 	let proofs = proofs
@@ -183,6 +303,6 @@ pub fn verify_proof_case(root: &str, leaf: &str, proofs: Vec<&str>) {
 			bytes
 		})
 		.collect::<Vec<[u8; 32]>>();
-	let proof_root = proof_root(leaf_hash, proofs);
+	let proof_root = proof_root::<H>(leaf_hash, proofs);
 	assert_eq!(root, hex::encode(proof_root));
 }
@@ -0,0 +1,143 @@
+use super::prelude::*;
+use frame_support::{
+	parameter_types,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
+use pallet_airdrop::migrations::v2;
+
+parameter_types! {
+	pub const LegacyVestingTerms: types::VestingTerms = mock::VESTING_TERMS;
+}
+
+/// Run given migration along with it's try-runtime checks when enabled
+fn run_migration<Migration: OnRuntimeUpgrade>() {
+	#[cfg(feature = "try-runtime")]
+	let state = Migration::pre_upgrade().unwrap();
+
+	Migration::on_runtime_upgrade();
+
+	#[cfg(feature = "try-runtime")]
+	Migration::post_upgrade(state).unwrap();
+}
+
+fn old_snapshot(
+	ice_address: types::AccountIdOf<Test>,
+	amount: types::BalanceOf<Test>,
+) -> v2::OldSnapshotInfo<Test> {
+	v2::OldSnapshotInfo {
+		ice_address,
+		amount,
+		defi_user: true,
+		done_instant: false,
+		done_vesting: false,
+		vesting_block_number: None,
+		instant_block_number: None,
+		initial_transfer: 0,
+	}
+}
+
+#[test]
+fn migrate_to_v2() {
+	mock::new_test_ext().execute_with(|| {
+		let merkle_root = [1u8; 32];
+		let creditor = samples::ACCOUNT_ID[0];
+		let unclaimed_icon = [1u8; 20];
+		let unclaimed_ice = samples::ACCOUNT_ID[1];
+		let claimed_icon = [2u8; 20];
+		let claimed_ice = samples::ACCOUNT_ID[2];
+
+		// Populate storage as it was before campaigns
+		StorageVersion::new(0).put::<AirdropModule>();
+		v2::StorageVersion::<Test>::put(1);
+		v2::MerkleRoot::<Test>::put(merkle_root);
+		v2::CreditorAccount::<Test>::put(creditor);
+
+		v2::IconSnapshotMap::<Test>::insert(unclaimed_icon, old_snapshot(unclaimed_ice, 1_000));
+		pallet_airdrop::IceIconMap::<Test>::insert(unclaimed_ice, unclaimed_icon);
+
+		let mut claimed = old_snapshot(claimed_ice, 2_000);
+		claimed.done_instant = true;
+		claimed.done_vesting = true;
+		claimed.instant_block_number = Some(5);
+		claimed.vesting_block_number = Some(5);
+		claimed.initial_transfer = 800;
+		v2::IconSnapshotMap::<Test>::insert(claimed_icon, claimed);
+		pallet_airdrop::IceIconMap::<Test>::insert(claimed_ice, claimed_icon);
+
+		run_migration::<v2::MigrateToV2<Test, LegacyVestingTerms>>();
+
+		assert_eq!(
+			AirdropModule::on_chain_storage_version(),
+			pallet_airdrop::STORAGE_VERSION
+		);
+		assert!(!v2::StorageVersion::<Test>::exists());
+		assert!(!v2::MerkleRoot::<Test>::exists());
+		assert!(!v2::CreditorAccount::<Test>::exists());
+
+		let campaign = AirdropModule::get_campaign(0).unwrap();
+		assert_eq!(campaign.merkle_root, merkle_root);
+		assert_eq!(campaign.creditor, creditor);
+		assert_eq!(AirdropModule::get_creditor_campaign(&creditor), Some(0));
+		assert_eq!(campaign.vesting_terms, mock::VESTING_TERMS);
+		assert_eq!(campaign.state, types::CampaignState::Open);
+		assert_eq!(AirdropModule::get_next_campaign_id(), 1);
+
+		// Snapshots are moved in following blocks. Claims wait for it
+		assert!(v2::is_migrating::<Test>());
+		assert_eq!(AirdropModule::get_distributed_amount(0), 0);
+		assert_err!(
+			AirdropModule::ensure_campaign_active(0),
+			PalletError::SnapshotMigrationOngoing
+		);
+
+		// Only single snapshot is moved when limited to one entry
+		v2::migrate_snapshots::<Test>(1);
+		assert!(v2::is_migrating::<Test>());
+		assert_eq!(
+			pallet_airdrop::IconSnapshotMap::<Test>::iter_prefix(0).count(),
+			1
+		);
+
+		// Rest is moved from on_initialize
+		AirdropModule::on_initialize(1);
+		assert!(!v2::is_migrating::<Test>());
+		assert_ok!(AirdropModule::ensure_campaign_active(0));
+		assert_eq!(AirdropModule::get_distributed_amount(0), 2_000);
+
+		// Snapshots are moved under campaign 0 and legacy keys are removed
+		assert!(!v2::IconSnapshotMap::<Test>::contains_key(unclaimed_icon));
+		assert!(!v2::IconSnapshotMap::<Test>::contains_key(claimed_icon));
+
+		let unclaimed = AirdropModule::get_icon_snapshot_map(0, unclaimed_icon).unwrap();
+		assert_eq!(unclaimed.ice_address, unclaimed_ice);
+		assert_eq!(unclaimed.amount, 1_000);
+		assert_eq!(unclaimed.vesting_terms, None);
+		assert!(!unclaimed.done_instant);
+
+		let claimed = AirdropModule::get_icon_snapshot_map(0, claimed_icon).unwrap();
+		assert_eq!(claimed.ice_address, claimed_ice);
+		assert_eq!(claimed.amount, 2_000);
+		assert_eq!(claimed.initial_transfer, 800);
+		assert_eq!(claimed.instant_block_number, Some(5));
+		assert_eq!(claimed.vesting_block_number, Some(5));
+		assert_eq!(claimed.vesting_terms, Some(mock::VESTING_TERMS));
+		assert!(claimed.done_instant && claimed.done_vesting);
+	});
+}
+
+#[test]
+fn migrate_to_v2_skip_migrated_storage() {
+	minimal_test_ext().execute_with(|| {
+		let campaign = AirdropModule::get_campaign(0).unwrap();
+		assert_eq!(
+			AirdropModule::on_chain_storage_version(),
+			pallet_airdrop::STORAGE_VERSION
+		);
+
+		v2::MigrateToV2::<Test, LegacyVestingTerms>::on_runtime_upgrade();
+
+		assert!(!v2::is_migrating::<Test>());
+		assert_eq!(AirdropModule::get_campaign(0).unwrap(), campaign);
+		assert_eq!(AirdropModule::get_next_campaign_id(), 1);
+	});
+}
@@ -1,19 +1,21 @@
-use crate::{self as pallet_airdrop, types};
-use core::marker::PhantomData;
+use crate::{self as pallet_airdrop, offchain::ClaimSubmitter, types};
+use core::{cell::RefCell, marker::PhantomData};
 
 use frame_support::{
 	parameter_types,
-	traits::{ConstU32, WithdrawReasons},
+	traits::{ConstU32, ConstU64, UnixTime, WithdrawReasons},
 };
 use frame_system as system;
 use pallet_balances;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestXt},
 	traits::{BlakeTwo256, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+/// Transaction submitted by offchain worker
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
 type Index = u64;
@@ -21,6 +23,93 @@ type BlockNumber = u64;
 
 pub struct TestValidator<T>(PhantomData<T>);
 
+thread_local! {
+	/// Batches handed over by offchain worker for submission
+	pub static SUBMITTED_CLAIMS: RefCell<Vec<types::UserClaimBatch<Test>>> = RefCell::new(vec![]);
+	/// Whether offchain worker is able to submit claims
+	pub static CAN_SUBMIT_CLAIMS: RefCell<bool> = RefCell::new(true);
+	/// Whether submitting claims from offchain worker succeed
+	pub static SUBMIT_CLAIMS_SUCCEED: RefCell<bool> = RefCell::new(true);
+	/// Whether claims are submitted as signed transaction as the runtime does
+	pub static SIGN_CLAIMS: RefCell<bool> = RefCell::new(false);
+	/// Unix time in milliseconds as seen by pallet
+	pub static NOW: RefCell<u64> = RefCell::new(0);
+}
+
+/// Clock whose time is set by tests through `NOW`
+pub struct TestUnixTime;
+
+impl UnixTime for TestUnixTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(NOW.with(|now| *now.borrow()))
+	}
+}
+
+/// Time after which signed message of samples are not accepted anymore
+pub const SIGNED_CLAIM_VALIDITY: u64 = 60_000;
+
+type SignedSubmitter = pallet_airdrop::offchain::SignedClaimSubmitter<Test, TestAuthId>;
+
+pub struct TestClaimSubmitter;
+
+impl ClaimSubmitter<Test> for TestClaimSubmitter {
+	fn can_submit() -> bool {
+		if SIGN_CLAIMS.with(|sign| *sign.borrow()) {
+			return SignedSubmitter::can_submit();
+		}
+		CAN_SUBMIT_CLAIMS.with(|can_submit| *can_submit.borrow())
+	}
+
+	fn submit(claims: types::UserClaimBatch<Test>) -> bool {
+		if SIGN_CLAIMS.with(|sign| *sign.borrow()) {
+			return SignedSubmitter::submit(claims);
+		}
+		SUBMITTED_CLAIMS.with(|submitted| submitted.borrow_mut().push(claims));
+		SUBMIT_CLAIMS_SUCCEED.with(|succeed| *succeed.borrow())
+	}
+}
+
+/// Server key crypto over the plain sr25519 account of this mock
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<sp_core::sr25519::Public, sp_core::sr25519::Signature>
+	for TestAuthId
+{
+	type RuntimeAppPublic = pallet_airdrop::offchain::crypto::Public;
+	type GenericSignature = sp_core::sr25519::Signature;
+	type GenericPublic = sp_core::sr25519::Public;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = sp_core::sr25519::Public;
+	type Signature = sp_core::sr25519::Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<A: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: Self::Public,
+		_account: Self::AccountId,
+		nonce: Index,
+	) -> Option<(
+		RuntimeCall,
+		<Extrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload,
+	)> {
+		Some((call, (nonce, ())))
+	}
+}
+
 impl types::MerkelProofValidator<Test> for TestValidator<Test> {
 	fn validate(
 		_root_hash: types::MerkleHash,
@@ -38,7 +127,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		AirdropModule: pallet_airdrop::{Pallet, Call, Storage, Event<T>},
+		AirdropModule: pallet_airdrop::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
@@ -82,23 +171,40 @@ parameter_types! {
 	pub const VestingMinTransfer: Balance = 1000;
 }
 
+parameter_types! {
+	pub UnclaimedFundsBeneficiary: sp_core::sr25519::Public =
+		sp_core::sr25519::Public::from_raw([7u8; 32]);
+	pub CreditorTopUpSource: sp_core::sr25519::Public =
+		sp_core::sr25519::Public::from_raw([8u8; 32]);
+}
+
 impl pallet_airdrop::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type AirdropWeightInfo = pallet_airdrop::weights::AirDropWeightInfo<Test>;
 	type BalanceTypeConversion = sp_runtime::traits::ConvertInto;
 	type MerkelProofValidator = TestValidator<Test>;
+	type MerkleLeafEncoder = pallet_airdrop::merkle::IconLeafEncoder;
+	type MerkleNodeHasher = pallet_airdrop::merkle::Blake2MerkleHasher;
+	type SourceChainVerifier = pallet_airdrop::icon::IconVerifier<ConstU64<1>>;
 	type MaxProofSize = ConstU32<10>;
-
-	const VESTING_TERMS: types::VestingTerms = {
-		types::VestingTerms {
-			defi_instant_percentage: 40,
-			non_defi_instant_percentage: 30,
-			vesting_period: 5_256_000,
-		}
-	};
+	type MaxClaimBatchSize = ConstU32<10>;
+	type UnsignedClaimPriority = ConstU64<100>;
+	type UnsignedClaimLongevity = ConstU64<64>;
+	type UnclaimedFundsBeneficiary = UnclaimedFundsBeneficiary;
+	type CreditorTopUpSource = CreditorTopUpSource;
+	type MaxFundedCampaigns = ConstU32<2>;
+	type UnixTime = TestUnixTime;
+	type SignedClaimValidity = ConstU64<SIGNED_CLAIM_VALIDITY>;
+	type OffchainClaimSubmitter = TestClaimSubmitter;
 }
 
+pub const VESTING_TERMS: types::VestingTerms = types::VestingTerms {
+	defi_instant_percentage: 40,
+	non_defi_instant_percentage: 30,
+	vesting_period: 5_256_000,
+};
+
 impl pallet_balances::Config for Test {
 	type Balance = Balance;
 	type DustRemoval = ();
@@ -127,8 +233,13 @@ impl pallet_vesting::Config for Test {
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default()
+	use sp_keystore::{testing::KeyStore, KeystoreExt};
+
+	let mut ext: sp_io::TestExternalities = system::GenesisConfig::default()
 		.build_storage::<Test>()
 		.unwrap()
-		.into()
+		.into();
+	// Benchmarks sign claims with keys generated on the go
+	ext.register_extension(KeystoreExt(std::sync::Arc::new(KeyStore::new())));
+	ext
 }
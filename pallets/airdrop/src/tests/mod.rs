@@ -1,13 +1,23 @@
+mod arbitrary_amount;
+mod campaign;
+mod creditor_funding;
+mod evm_claim;
 mod exchange_claim;
+mod icon_payload;
 mod merkle_tests;
+mod migrations;
 pub mod mock;
+mod offchain;
+mod partial_claim;
+mod replay_protection;
+mod runtime_api;
 mod signature_validation;
 mod user_claim;
 mod utility_functions;
 pub mod prelude {
 	pub use super::{
-		force_get_creditor_account, get_last_event, minimal_test_ext, mock, run_to_block, samples,
-		set_creditor_balance, transfer_to_creditor,
+		force_get_creditor_account, get_last_event, get_pallet_events, minimal_test_ext, mock,
+		run_to_block, samples, set_creditor_balance, to_payload, transfer_to_creditor,
 	};
 	pub use crate as pallet_airdrop;
 	pub use codec::Encode;
@@ -18,7 +28,7 @@ pub mod prelude {
 	pub use hex_literal::hex as decode_hex;
 	pub use mock::{AirdropModule, RuntimeOrigin, Test};
 	pub use pallet_airdrop::{tests, transfer, types, utils};
-	pub use sp_core::bytes;
+	pub use sp_core::{bytes, sr25519};
 	pub use sp_runtime::traits::{Bounded, IdentifyAccount, Saturating};
 
 	pub type PalletError = pallet_airdrop::Error<Test>;
@@ -35,13 +45,14 @@ use prelude::*;
 pub struct UserClaimTestCase {
 	pub icon_address: [u8; 20],
 	pub ice_address: types::IceAddress,
-	pub message: types::RawPayload,
+	pub message: types::RawPayload<Test>,
 	pub icon_signature: [u8; 65],
-	pub ice_signature: [u8; 64],
+	pub ice_signature: types::IceSignature,
 	pub amount: u128,
 	pub defi_user: bool,
 	pub merkle_proofs: BoundedVec<types::MerkleHash, ConstU32<10>>,
 	pub merkle_root: [u8; 32],
+	pub expiry: types::BlockNumberOf<Test>,
 }
 
 impl Default for UserClaimTestCase {
@@ -52,21 +63,27 @@ impl Default for UserClaimTestCase {
 		Self {
 			icon_address: samples::VALID_ICON_WALLET,
 			ice_address: samples::VALID_ICE_ADDRESS,
-			message: samples::VALID_MESSAGE,
+			message: to_payload(samples::VALID_MESSAGE),
 			icon_signature: samples::VALID_ICON_SIGNATURE,
-			ice_signature: samples::VALID_ICE_SIGNATURE,
+			ice_signature: sr25519::Signature::from_raw(samples::VALID_ICE_SIGNATURE).into(),
 			amount: 12_000_000,
 			defi_user: true,
 			merkle_proofs: bounded_proofs,
 			merkle_root: root,
+			expiry: 1_000,
 		}
 	}
 }
 
+/// Signed message as accepted by claim calls
+pub fn to_payload(message: &[u8]) -> types::RawPayload<Test> {
+	message.to_vec().try_into().unwrap()
+}
+
 pub mod samples {
 
 	use super::decode_hex;
-	use super::types::{IconAddress, IconSignature, RawPayload};
+	use super::types::{IconAddress, IconSignature};
 	use sp_core::sr25519;
 
 	pub const ACCOUNT_ID: &[sr25519::Public] = &[
@@ -94,7 +111,7 @@ pub mod samples {
 	);
 
 	pub const VALID_ICON_SIGNATURE:IconSignature = decode_hex!("9ee3f663175691ad82f4fbb0cfd0594652e3a034e3b6934b0e4d4a60437ba4043c89d2ffcb7b0af49ed0744ce773612d7ebcdf3a5b035c247706050e0a0033e401");
-	pub const VALID_MESSAGE: RawPayload = *b"icx_sendTransaction.data.{method.transfer.params.{wallet.b6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.version.0x3";
+	pub const VALID_MESSAGE: &[u8] = b"icx_sendTransaction.data.{method.transfer.params.{wallet.b6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.version.0x3";
 	pub const VALID_ICON_WALLET: IconAddress =
 		decode_hex!("b48f3bd3862d4a489fb3c9b761c4cfb20b34a645");
 	pub const VALID_ICE_ADDRESS: [u8; 32] =
@@ -115,6 +132,7 @@ pub fn minimal_test_ext() -> sp_io::TestExternalities {
 	pallet_airdrop::GenesisConfig::<Test> {
 		creditor_account: account_id,
 		merkle_root: hex!["4c59b428da385567a6d42ee1881ecbe43cf30bf8c4499887b7c6f689d23d4672"],
+		vesting_terms: mock::VESTING_TERMS,
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
@@ -142,6 +160,17 @@ pub fn get_last_event() -> Option<<Test as frame_system::Config>::RuntimeEvent>
 		.map(|v| v.event)
 }
 
+/// All events deposited by airdrop pallet so far, in order
+pub fn get_pallet_events() -> Vec<pallet_airdrop::Event<Test>> {
+	<frame_system::Pallet<Test>>::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			mock::RuntimeEvent::AirdropModule(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
 pub fn set_creditor_balance(balance: u64) {
 	let creditor_account = force_get_creditor_account::<Test>();
 	let deposit_res = <Test as pallet_airdrop::Config>::Currency::set_balance(
@@ -185,7 +214,7 @@ pub fn transfer_to_creditor(sponsor: &types::AccountIdOf<Test>, amount: types::B
 }
 
 pub fn force_get_creditor_account<T: pallet_airdrop::Config>() -> types::AccountIdOf<T> {
-	pallet_airdrop::Pallet::<T>::get_creditor_account().expect("creditor account not set")
+	pallet_airdrop::Pallet::<T>::get_creditor_account(0).expect("creditor account not set")
 }
 
 impl Default for types::SnapshotInfo<Test> {
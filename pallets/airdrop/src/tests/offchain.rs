@@ -0,0 +1,244 @@
+use super::prelude::*;
+use crate::tests::UserClaimTestCase;
+use codec::Decode;
+use frame_support::traits::{Get, Hooks};
+use mock::{System, CAN_SUBMIT_CLAIMS, SIGN_CLAIMS, SUBMITTED_CLAIMS, SUBMIT_CLAIMS_SUCCEED};
+use pallet_airdrop_runtime_api::{QueuedClaim, CLAIM_QUEUE_KEY};
+use sp_core::offchain::{
+	testing::{TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+use sp_runtime::{offchain::storage::StorageValueRef, traits::Dispatchable};
+use std::sync::Arc;
+
+fn offchain_test_ext() -> sp_io::TestExternalities {
+	let (offchain, _state) = TestOffchainExt::new();
+	let mut ext = minimal_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	// Claims creditor cannot pay out are never relayed
+	ext.execute_with(|| set_creditor_balance(10_000_0000));
+
+	SUBMITTED_CLAIMS.with(|submitted| submitted.borrow_mut().clear());
+	CAN_SUBMIT_CLAIMS.with(|can_submit| *can_submit.borrow_mut() = true);
+	SUBMIT_CLAIMS_SUCCEED.with(|succeed| *succeed.borrow_mut() = true);
+	SIGN_CLAIMS.with(|sign| *sign.borrow_mut() = false);
+
+	ext
+}
+
+fn to_queued_claim(case: UserClaimTestCase) -> QueuedClaim {
+	QueuedClaim {
+		campaign_id: 0,
+		icon_address: case.icon_address,
+		ice_address: case.ice_address,
+		message: case.message.as_ref().to_vec(),
+		icon_signature: case.icon_signature,
+		ice_signature: case.ice_signature,
+		amount: case.amount,
+		defi_user: case.defi_user,
+		proofs: case.merkle_proofs.to_vec(),
+		expiry: case.expiry as u32,
+	}
+}
+
+fn set_queue(claims: Vec<QueuedClaim>) {
+	StorageValueRef::persistent(CLAIM_QUEUE_KEY).set(&claims);
+}
+
+fn get_queue() -> Vec<QueuedClaim> {
+	StorageValueRef::persistent(CLAIM_QUEUE_KEY)
+		.get::<Vec<QueuedClaim>>()
+		.unwrap()
+		.unwrap_or_default()
+}
+
+/// Claims submitted by offchain worker that are yet to be seen completed
+fn pending_claims() -> Vec<QueuedClaim> {
+	StorageValueRef::persistent(pallet_airdrop::offchain::SUBMITTED_CLAIMS_KEY)
+		.get::<Vec<pallet_airdrop::offchain::SubmittedClaim<types::BlockNumberOf<Test>>>>()
+		.unwrap()
+		.unwrap_or_default()
+		.into_iter()
+		.map(|submitted| submitted.claim)
+		.collect()
+}
+
+fn submitted_claims() -> Vec<Vec<types::UserClaimRequest<Test>>> {
+	SUBMITTED_CLAIMS.with(|submitted| {
+		submitted
+			.borrow()
+			.iter()
+			.map(|batch| batch.to_vec())
+			.collect()
+	})
+}
+
+#[test]
+fn relay_valid_claims() {
+	offchain_test_ext().execute_with(|| {
+		let valid_claim = to_queued_claim(UserClaimTestCase::default());
+		let mut invalid_claim = valid_claim.clone();
+		invalid_claim.icon_address = samples::ICON_ADDRESS[0];
+		let mut malformed_claim = valid_claim.clone();
+		malformed_claim
+			.message
+			.resize(pallet_airdrop::icon::MAX_PAYLOAD_LENGTH as usize + 1, b'0');
+
+		set_queue(vec![invalid_claim, valid_claim.clone(), malformed_claim]);
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+
+		assert_eq!(
+			submitted_claims(),
+			vec![vec![pallet_airdrop::offchain::into_claim_request::<Test>(
+				valid_claim
+			)
+			.unwrap()]]
+		);
+		assert!(get_queue().is_empty());
+	});
+}
+
+#[test]
+fn relay_single_batch_per_run() {
+	offchain_test_ext().execute_with(|| {
+		let claim = to_queued_claim(UserClaimTestCase::default());
+		let batch_size = <Test as pallet_airdrop::Config>::MaxClaimBatchSize::get() as usize;
+		set_queue(vec![claim; batch_size + 2]);
+
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+		assert_eq!(submitted_claims()[0].len(), batch_size);
+		assert_eq!(get_queue().len(), 2);
+
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+		assert_eq!(submitted_claims()[1].len(), 2);
+		assert!(get_queue().is_empty());
+	});
+}
+
+#[test]
+fn requeue_on_failed_submission() {
+	offchain_test_ext().execute_with(|| {
+		let valid_claim = to_queued_claim(UserClaimTestCase::default());
+		let mut invalid_claim = valid_claim.clone();
+		invalid_claim.icon_address = samples::ICON_ADDRESS[0];
+		SUBMIT_CLAIMS_SUCCEED.with(|succeed| *succeed.borrow_mut() = false);
+
+		set_queue(vec![invalid_claim, valid_claim.clone()]);
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+
+		// Only the valid claim is kept for retry
+		assert_eq!(submitted_claims().len(), 1);
+		assert_eq!(get_queue(), vec![valid_claim]);
+	});
+}
+
+#[test]
+fn submitted_claim_is_kept_until_completed() {
+	offchain_test_ext().execute_with(|| {
+		let claim = to_queued_claim(UserClaimTestCase::default());
+		set_queue(vec![claim.clone()]);
+
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+		assert!(get_queue().is_empty());
+		assert_eq!(pending_claims(), vec![claim.clone()]);
+
+		// Not submitted again while it's transaction can still be included
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+		assert_eq!(submitted_claims().len(), 1);
+		assert_eq!(pending_claims(), vec![claim.clone()]);
+
+		AirdropModule::consume_payload(0, sp_io::hashing::blake2_256(&claim.message));
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+		assert_eq!(submitted_claims().len(), 1);
+		assert!(pending_claims().is_empty());
+	});
+}
+
+#[test]
+fn expired_submission_is_retried() {
+	offchain_test_ext().execute_with(|| {
+		let claim = to_queued_claim(UserClaimTestCase::default());
+		set_queue(vec![claim.clone()]);
+
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+		assert_eq!(submitted_claims().len(), 1);
+
+		// Transaction can no longer be included without completing the claim
+		let block_hash_count = <Test as frame_system::Config>::BlockHashCount::get();
+		System::set_block_number(System::block_number() + block_hash_count);
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+
+		assert_eq!(submitted_claims().len(), 2);
+		assert_eq!(submitted_claims()[0], submitted_claims()[1]);
+		assert!(get_queue().is_empty());
+		assert_eq!(pending_claims(), vec![claim]);
+	});
+}
+
+#[test]
+fn queue_untouched_without_submitter() {
+	offchain_test_ext().execute_with(|| {
+		let claim = to_queued_claim(UserClaimTestCase::default());
+		CAN_SUBMIT_CLAIMS.with(|can_submit| *can_submit.borrow_mut() = false);
+
+		set_queue(vec![claim.clone()]);
+		pallet_airdrop::offchain::process_claim_queue::<Test>();
+
+		assert!(submitted_claims().is_empty());
+		assert_eq!(get_queue(), vec![claim]);
+	});
+}
+
+#[test]
+fn queued_claim_is_submitted_and_dispatched() {
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = KeyStore::new();
+	let server_account =
+		SyncCryptoStore::sr25519_generate_new(&keystore, pallet_airdrop::offchain::KEY_TYPE, None)
+			.unwrap();
+
+	let mut ext = offchain_test_ext();
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt(Arc::new(keystore)));
+	SIGN_CLAIMS.with(|sign| *sign.borrow_mut() = true);
+
+	ext.execute_with(|| {
+		let case = UserClaimTestCase::default();
+		set_queue(vec![to_queued_claim(case.clone())]);
+
+		// Nothing is relayed until server key is in keystore
+		AirdropModule::offchain_worker(0);
+		assert!(pool_state.read().transactions.is_empty());
+		assert_eq!(get_queue().len(), 1);
+
+		assert_ok!(AirdropModule::set_airdrop_server_account(
+			RuntimeOrigin::root(),
+			server_account
+		));
+		AirdropModule::offchain_worker(0);
+		assert!(get_queue().is_empty());
+
+		let raw_tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = mock::Extrinsic::decode(&mut &raw_tx[..]).unwrap();
+		assert_eq!(tx.signature, Some((0, ())));
+		assert_eq!(
+			tx.call,
+			mock::RuntimeCall::AirdropModule(PalletCall::dispatch_user_claims_batch {
+				claims: vec![pallet_airdrop::offchain::into_claim_request::<Test>(
+					to_queued_claim(case.clone())
+				)
+				.unwrap()]
+				.try_into()
+				.unwrap(),
+			})
+		);
+
+		// Transaction is signed by server account so it is accepted as such
+		assert_ok!(tx.call.dispatch(RuntimeOrigin::signed(server_account)));
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, case.icon_address).unwrap();
+		assert!(snapshot.done_instant);
+	});
+}
@@ -0,0 +1,235 @@
+use super::prelude::*;
+use crate::tests::UserClaimTestCase;
+use frame_support::dispatch::Pays;
+
+/// Make a claim of given case while all vesting slots of the claimer
+/// are used so that only instant transfer succeed
+fn make_partial_claim(case: &UserClaimTestCase) -> types::AccountIdOf<Test> {
+	let ice_account = AirdropModule::convert_to_account_id(case.ice_address).unwrap();
+
+	let vesting_count_limit = <Test as pallet_vesting::Config>::MAX_VESTING_SCHEDULES;
+	for _ in 0..vesting_count_limit {
+		assert_ok!(pallet_vesting::Pallet::<Test>::vested_transfer(
+			RuntimeOrigin::signed(force_get_creditor_account::<Test>()),
+			ice_account.clone(),
+			types::VestingInfoOf::<Test>::new(10_000, 2000, 5),
+		));
+	}
+
+	let case = case.clone();
+	assert_ok!(AirdropModule::dispatch_user_claim(
+		RuntimeOrigin::root(),
+		0,
+		case.icon_address,
+		case.ice_address,
+		case.message,
+		case.icon_signature,
+		case.ice_signature,
+		case.amount,
+		case.defi_user,
+		case.merkle_proofs,
+		case.expiry,
+	));
+
+	ice_account
+}
+
+/// Release the schedules put while making partial claim
+fn free_vesting_slots(ice_account: &types::AccountIdOf<Test>) {
+	run_to_block(12);
+	assert_ok!(pallet_vesting::Pallet::<Test>::vest(RuntimeOrigin::signed(
+		ice_account.clone()
+	)));
+}
+
+#[test]
+fn complete_partial_claim_by_claimer() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(Bounded::max_value());
+
+		let mut case = UserClaimTestCase::default();
+		case.amount = 10_u64.pow(18).into();
+		let ice_account = make_partial_claim(&case);
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, case.icon_address).unwrap();
+		assert!(snapshot.is_partial());
+		assert_eq!(
+			AirdropModule::partial_snapshots(None, 10).snapshots,
+			vec![pallet_airdrop_runtime_api::PartialSnapshot {
+				campaign_id: 0,
+				icon_address: case.icon_address.into(),
+				snapshot: snapshot.to_preview(),
+			}]
+		);
+
+		free_vesting_slots(&ice_account);
+		let balance_before =
+			<Test as pallet_airdrop::Config>::Currency::total_balance(&ice_account);
+
+		// Claimer pays for the retry
+		let post_info = AirdropModule::complete_partial_claim(
+			RuntimeOrigin::signed(ice_account.clone()),
+			0,
+			case.icon_address,
+		)
+		.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, case.icon_address).unwrap();
+		assert!(snapshot.done_instant && snapshot.done_vesting);
+		assert_eq!(snapshot.vesting_block_number, Some(12));
+		assert_eq!(snapshot.instant_block_number, Some(1));
+		assert!(AirdropModule::partial_snapshots(None, 10)
+			.snapshots
+			.is_empty());
+
+		// Only vesting part is transferred
+		let vested_amount = case.amount - snapshot.initial_transfer;
+		assert_eq!(
+			<Test as pallet_airdrop::Config>::Currency::total_balance(&ice_account),
+			balance_before + vested_amount
+		);
+		match get_pallet_events().pop() {
+			Some(PalletEvent::ClaimSuccess {
+				instant_amount,
+				vested_amount: event_vested_amount,
+				skipped_phase,
+				..
+			}) => {
+				assert_eq!(instant_amount, 0);
+				assert_eq!(event_vested_amount, vested_amount);
+				assert_eq!(skipped_phase, Some(types::ClaimPhase::Instant));
+			}
+			other => panic!("Expected ClaimSuccess event. Got: {other:?}"),
+		}
+
+		// Nothing left to retry
+		assert_noop!(
+			AirdropModule::complete_partial_claim(RuntimeOrigin::root(), 0, case.icon_address),
+			PalletError::NotPartialClaim
+		);
+	});
+}
+
+#[test]
+fn complete_partial_claim_by_root_is_free() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(Bounded::max_value());
+
+		let mut case = UserClaimTestCase::default();
+		case.amount = 10_u64.pow(18).into();
+		let ice_account = make_partial_claim(&case);
+		free_vesting_slots(&ice_account);
+
+		let post_info =
+			AirdropModule::complete_partial_claim(RuntimeOrigin::root(), 0, case.icon_address)
+				.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, case.icon_address).unwrap();
+		assert!(snapshot.done_instant && snapshot.done_vesting);
+	});
+}
+
+#[test]
+fn complete_partial_claim_failing_again() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(Bounded::max_value());
+
+		let mut case = UserClaimTestCase::default();
+		case.amount = 10_u64.pow(18).into();
+		make_partial_claim(&case);
+
+		// Vesting slots are still in use
+		assert_err_ignore_postinfo!(
+			AirdropModule::complete_partial_claim(RuntimeOrigin::root(), 0, case.icon_address),
+			pallet_vesting::Error::<Test>::AtMaxVestingSchedules
+		);
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, case.icon_address).unwrap();
+		assert!(snapshot.is_partial());
+	});
+}
+
+#[test]
+fn complete_partial_claim_invalid() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(Bounded::max_value());
+
+		let mut case = UserClaimTestCase::default();
+		case.amount = 10_u64.pow(18).into();
+
+		// No snapshot exists yet
+		assert_noop!(
+			AirdropModule::complete_partial_claim(RuntimeOrigin::root(), 0, case.icon_address),
+			PalletError::NotPartialClaim
+		);
+
+		let ice_account = make_partial_claim(&case);
+		free_vesting_slots(&ice_account);
+
+		// Only claimer itself can retry besides root and server
+		assert_noop!(
+			AirdropModule::complete_partial_claim(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[1]),
+				0,
+				case.icon_address,
+			),
+			PalletError::DeniedOperation
+		);
+
+		// Retry respect the claim switch
+		let mut state = AirdropModule::get_airdrop_state();
+		state.block_claim_request = true;
+		assert_ok!(AirdropModule::update_airdrop_state(
+			RuntimeOrigin::root(),
+			state
+		));
+		assert_noop!(
+			AirdropModule::complete_partial_claim(
+				RuntimeOrigin::signed(ice_account),
+				0,
+				case.icon_address,
+			),
+			PalletError::NewClaimRequestBlocked
+		);
+	});
+}
+
+#[test]
+fn partial_snapshots_are_listed_in_pages() {
+	minimal_test_ext().execute_with(|| {
+		// Every other snapshot is partial
+		for i in 0..5_u8 {
+			let mut snapshot =
+				types::SnapshotInfo::<Test>::new(samples::ACCOUNT_ID[1], false, 1_000);
+			snapshot.done_instant = true;
+			snapshot.done_vesting = i % 2 == 1;
+			pallet_airdrop::IconSnapshotMap::<Test>::insert(0, [i; 20], snapshot);
+		}
+
+		let mut pages = vec![];
+		let mut start_key = None;
+		loop {
+			let page = AirdropModule::partial_snapshots(start_key, 2);
+			pages.push(page.snapshots.len());
+			start_key = page.next_key;
+			if start_key.is_none() {
+				break;
+			}
+		}
+
+		// Two snapshots scanned per page and each partial one listed once
+		assert_eq!(pages.len(), 3);
+		assert_eq!(pages.iter().sum::<usize>(), 3);
+
+		// Whole map fits in a single page
+		let page = AirdropModule::partial_snapshots(None, 10);
+		assert_eq!(page.snapshots.len(), 3);
+		assert_eq!(page.next_key, None);
+	});
+}
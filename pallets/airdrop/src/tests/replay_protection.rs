@@ -0,0 +1,289 @@
+use super::prelude::*;
+use crate::tests::UserClaimTestCase;
+
+fn claim(case: UserClaimTestCase) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	AirdropModule::dispatch_user_claim(
+		RuntimeOrigin::root(),
+		0,
+		case.icon_address,
+		case.ice_address,
+		case.message,
+		case.icon_signature,
+		case.ice_signature,
+		case.amount,
+		case.defi_user,
+		case.merkle_proofs,
+		case.expiry,
+	)
+}
+
+fn payload_hash(case: &UserClaimTestCase) -> types::PayloadHash {
+	sp_io::hashing::blake2_256(case.message.as_ref())
+}
+
+#[test]
+fn expired_claim() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(5);
+		set_creditor_balance(10_000_0000);
+
+		let mut case = UserClaimTestCase::default();
+		case.expiry = 4;
+		assert_noop!(claim(case.clone()), PalletError::ClaimExpired);
+
+		// Expiry block itself is still valid
+		case.expiry = 5;
+		assert_ok!(claim(case));
+	});
+}
+
+#[test]
+fn zero_timestamp_is_not_bound_by_time() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+
+		// Sample messages are signed with zero timestamp as frontends do.
+		// Clock of a live chain is far past `SignedClaimValidity`
+		mock::NOW.with(|now| *now.borrow_mut() = 1_700_000_000_000);
+		assert_ok!(claim(UserClaimTestCase::default()));
+	});
+}
+
+#[test]
+fn stale_signed_message() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		// Signed at unix time 1_677_721_587_120 in milliseconds
+		let message = "icx_sendTransaction.data.{method.transfer.params.{wallet.0xb6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxeae38983e1a9da83663d539053ee5fef1c41a028.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x5f5e0ff3b7a1c.to.hxeae38983e1a9da83663d539053ee5fef1c41a028.version.0x3".as_bytes();
+		let signed_at = 1_677_721_587_120;
+		let icon_address = samples::ICON_ADDRESS[0];
+
+		// Expiry block given by submitter cannot keep it valid
+		// past `SignedClaimValidity`
+		mock::NOW.with(|now| *now.borrow_mut() = signed_at + mock::SIGNED_CLAIM_VALIDITY + 1);
+		assert_err!(
+			AirdropModule::ensure_not_replayed(0, &icon_address, message, u64::MAX),
+			PalletError::ClaimExpired
+		);
+
+		// Last millisecond of validity is still accepted
+		mock::NOW.with(|now| *now.borrow_mut() = signed_at + mock::SIGNED_CLAIM_VALIDITY);
+		assert_ok!(AirdropModule::ensure_not_replayed(
+			0,
+			&icon_address,
+			message,
+			u64::MAX
+		));
+	});
+}
+
+#[test]
+fn payload_consumed_on_success_only() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(3);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+
+		let mut invalid_case = case.clone();
+		invalid_case.ice_signature = sr25519::Signature::from_raw([0; 64]).into();
+		assert_noop!(claim(invalid_case), PalletError::InvalidIceSignature);
+		assert_eq!(
+			AirdropModule::get_payload_consumption(0, payload_hash(&case)),
+			None
+		);
+
+		assert_ok!(claim(case.clone()));
+		assert_eq!(
+			AirdropModule::get_payload_consumption(0, payload_hash(&case)),
+			Some(3)
+		);
+	});
+}
+
+#[test]
+fn replay_after_state_change() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		assert_ok!(claim(case.clone()));
+
+		// Even if snapshot is gone and root is set again
+		// same signed message can not be used again
+		pallet_airdrop::IconSnapshotMap::<Test>::remove(0, case.icon_address);
+		pallet_airdrop::IceIconMap::<Test>::remove(
+			AirdropModule::convert_to_account_id(case.ice_address).unwrap(),
+		);
+		assert_ok!(AirdropModule::change_merkle_root(
+			RuntimeOrigin::root(),
+			0,
+			case.merkle_root
+		));
+		assert_noop!(claim(case), PalletError::PayloadAlreadyUsed);
+	});
+}
+
+#[test]
+fn replay_inside_batch() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+
+		let request = types::UserClaimRequest::<Test> {
+			campaign_id: 0,
+			icon_address: case.icon_address,
+			ice_address: case.ice_address,
+			message: case.message,
+			icon_signature: case.icon_signature,
+			ice_signature: case.ice_signature,
+			amount: case.amount,
+			defi_user: case.defi_user,
+			proofs: case.merkle_proofs,
+			expiry: case.expiry,
+		};
+		let claims: types::UserClaimBatch<Test> =
+			vec![request.clone(), request].try_into().unwrap();
+
+		assert_ok!(AirdropModule::dispatch_user_claims_batch(
+			RuntimeOrigin::root(),
+			claims
+		));
+		assert!(get_pallet_events().contains(&PalletEvent::ClaimFailed {
+			campaign_id: 0,
+			icon_address: case.icon_address,
+			error: PalletError::PayloadAlreadyUsed.into(),
+		}));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::ClaimBatchProcessed {
+					succeeded: 1,
+					failed: 1
+				}
+				.into()
+			)
+		);
+	});
+}
+
+#[test]
+fn revoke_icon_address() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(2);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+
+		assert_noop!(
+			AirdropModule::revoke_icon_address(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[1]),
+				case.icon_address
+			),
+			PalletError::DeniedOperation
+		);
+
+		// Server account is allowed as well as root
+		assert_ok!(AirdropModule::set_airdrop_server_account(
+			RuntimeOrigin::root(),
+			samples::ACCOUNT_ID[0].into_account()
+		));
+		assert_ok!(AirdropModule::revoke_icon_address(
+			RuntimeOrigin::signed(samples::ACCOUNT_ID[0]),
+			case.icon_address
+		));
+		assert_eq!(AirdropModule::get_revocation(case.icon_address), Some(2));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::IconAddressRevoked {
+					icon_address: case.icon_address
+				}
+				.into()
+			)
+		);
+
+		assert_noop!(
+			AirdropModule::revoke_icon_address(RuntimeOrigin::root(), case.icon_address),
+			PalletError::IconAddressRevoked
+		);
+		assert_noop!(claim(case), PalletError::IconAddressRevoked);
+	});
+}
+
+#[test]
+fn validate_unsigned_respect_expiry() {
+	use frame_support::pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned};
+
+	let to_call = |case: UserClaimTestCase| PalletCall::claim_unsigned {
+		campaign_id: 0,
+		icon_address: case.icon_address,
+		ice_address: case.ice_address,
+		message: case.message,
+		icon_signature: case.icon_signature,
+		ice_signature: case.ice_signature,
+		total_amount: case.amount,
+		defi_user: case.defi_user,
+		proofs: case.merkle_proofs,
+		expiry: case.expiry,
+	};
+
+	minimal_test_ext().execute_with(|| {
+		run_to_block(10);
+		set_creditor_balance(10_000_0000);
+		let mut case = UserClaimTestCase::default();
+
+		// Claim is not kept in pool past it's expiry
+		case.expiry = 15;
+		let validity =
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(case.clone()))
+				.unwrap();
+		assert_eq!(validity.longevity, 6);
+
+		case.expiry = 9;
+		assert_eq!(
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(case.clone())),
+			Err(InvalidTransaction::Stale.into())
+		);
+
+		// Consumed payload is stale as well
+		case.expiry = 1_000;
+		let payload_hash = payload_hash(&case);
+		pallet_airdrop::ConsumedPayloads::<Test>::insert(0, payload_hash, 1);
+		assert_eq!(
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(case)),
+			Err(InvalidTransaction::Stale.into())
+		);
+	});
+}
+
+#[test]
+fn payloads_cleared_after_sweep() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		assert_ok!(claim(case.clone()));
+
+		assert_ok!(AirdropModule::set_claim_deadline(
+			RuntimeOrigin::root(),
+			0,
+			Some(2)
+		));
+		run_to_block(3);
+		assert_ok!(AirdropModule::sweep_unclaimed_funds(
+			RuntimeOrigin::root(),
+			0
+		));
+		assert_eq!(AirdropModule::get_payloads_to_clear(0), Some(()));
+		assert!(AirdropModule::get_payload_consumption(0, payload_hash(&case)).is_some());
+
+		run_to_block(4);
+		assert_eq!(
+			AirdropModule::get_payload_consumption(0, payload_hash(&case)),
+			None
+		);
+		assert_eq!(AirdropModule::get_payloads_to_clear(0), None);
+	});
+}
@@ -0,0 +1,145 @@
+use super::prelude::*;
+use crate::tests::UserClaimTestCase;
+use pallet_airdrop_runtime_api::VestingSchedulePreview;
+
+fn claim(case: UserClaimTestCase) {
+	assert_ok!(AirdropModule::dispatch_user_claim(
+		RuntimeOrigin::root(),
+		0,
+		case.icon_address,
+		case.ice_address,
+		case.message,
+		case.icon_signature,
+		case.ice_signature,
+		case.amount,
+		case.defi_user,
+		case.merkle_proofs,
+		case.expiry,
+	));
+}
+
+#[test]
+fn preview_unclaimed() {
+	minimal_test_ext().execute_with(|| {
+		let case = UserClaimTestCase::default();
+
+		let preview =
+			AirdropModule::preview_claim(0, case.icon_address, case.amount, case.defi_user)
+				.unwrap();
+
+		// 40% of 12_000_000 is given instantly and rest is vested
+		// over 5_255_999 blocks with a minimum of 1 per block
+		assert_eq!(preview.instant_amount, 4_800_000);
+		assert_eq!(
+			preview.vesting_schedule,
+			Some(VestingSchedulePreview {
+				locked: 5_255_999,
+				per_block: 1,
+				starting_block: transfer::VESTING_APPLICABLE_FROM.into(),
+			})
+		);
+		assert_eq!(preview.remainder, 1_944_001);
+		assert!(!preview.already_claimed);
+		assert_eq!(preview.snapshot, None);
+
+		// Non defi user get different instant percentage
+		let preview =
+			AirdropModule::preview_claim(0, case.icon_address, case.amount, false).unwrap();
+		assert_eq!(preview.instant_amount, 3_600_000);
+	});
+}
+
+#[test]
+fn preview_claimed() {
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		claim(case.clone());
+
+		let preview =
+			AirdropModule::preview_claim(0, case.icon_address, case.amount, case.defi_user)
+				.unwrap();
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
+
+		assert!(preview.already_claimed);
+		assert_eq!(preview.snapshot, Some(snapshot.to_preview()));
+
+		let snapshot = preview.snapshot.unwrap();
+		assert_eq!(snapshot.instant_percentage, Some(40));
+		assert_eq!(snapshot.vesting_period, Some(5_256_000));
+	});
+}
+
+#[test]
+fn preview_match_transfer() {
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+		let ice_account = AirdropModule::convert_to_account_id(case.ice_address).unwrap();
+
+		let preview =
+			AirdropModule::preview_claim(0, case.icon_address, case.amount, case.defi_user)
+				.unwrap();
+		claim(case.clone());
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
+		assert_eq!(
+			snapshot.initial_transfer,
+			preview.instant_amount + preview.remainder
+		);
+		if !cfg!(feature = "no-vesting") {
+			let schedules = pallet_vesting::Pallet::<Test>::vesting(&ice_account).unwrap();
+			assert_eq!(
+				schedules[0].locked(),
+				preview.vesting_schedule.unwrap().locked
+			);
+		}
+	});
+}
+
+#[test]
+fn preview_unknown_campaign() {
+	minimal_test_ext().execute_with(|| {
+		let case = UserClaimTestCase::default();
+
+		assert_err!(
+			AirdropModule::preview_claim(5, case.icon_address, case.amount, case.defi_user),
+			PalletError::NoSuchCampaign
+		);
+	});
+}
+
+#[test]
+fn dry_run_merkle_proof() {
+	let icon_address = decode_hex!("a99344ea068864f8af6cbcf89328d6eb3d7e8c9c");
+	let root = decode_hex!("0ad37ff10c4e2f80b4f66c376077e664e5333fd6e256385cf7ff2b03952bb2e2");
+	let (leaf, proofs) = tests::to_test_case(samples::MERKLE_PROOF_SAMPLE);
+
+	minimal_test_ext().execute_with(|| {
+		assert_ok!(AirdropModule::change_merkle_root(
+			RuntimeOrigin::root(),
+			0,
+			root
+		));
+
+		let check =
+			AirdropModule::dry_run_merkle_proof(0, &icon_address, 0, true, proofs.clone()).unwrap();
+		assert_eq!(check.leaf_hash, leaf.into());
+		assert_eq!(check.computed_root, root.into());
+		assert_eq!(check.stored_root, root.into());
+		assert!(check.is_valid);
+
+		// Different leaf encoding produce different root
+		let check = AirdropModule::dry_run_merkle_proof(0, &icon_address, 0, false, proofs.clone())
+			.unwrap();
+		assert_ne!(check.leaf_hash, leaf.into());
+		assert_ne!(check.computed_root, root.into());
+		assert_eq!(check.stored_root, root.into());
+		assert!(!check.is_valid);
+
+		assert_err!(
+			AirdropModule::dry_run_merkle_proof(5, &icon_address, 0, true, proofs),
+			PalletError::NoSuchCampaign
+		);
+	});
+}
@@ -14,6 +14,7 @@ fn claim_success() {
 
 		assert_ok!(AirdropModule::dispatch_user_claim(
 			RuntimeOrigin::signed(AirdropModule::get_airdrop_server_account().unwrap()),
+			0,
 			case.icon_address,
 			case.ice_address,
 			case.message,
@@ -22,12 +23,13 @@ fn claim_success() {
 			case.amount,
 			case.defi_user,
 			case.merkle_proofs,
+			case.expiry,
 		));
 
 		let ice_account = AirdropModule::convert_to_account_id(case.ice_address.clone()).unwrap();
 		let total_balance = <Test as Config>::Currency::total_balance(&ice_account);
 		let usable_balance = <Test as Config>::Currency::usable_balance(&ice_account);
-		let snapshot = <pallet_airdrop::IconSnapshotMap<Test>>::get(&case.icon_address).unwrap();
+		let snapshot = <pallet_airdrop::IconSnapshotMap<Test>>::get(0, &case.icon_address).unwrap();
 		let mapped_icon_wallet = AirdropModule::get_ice_to_icon_map(&ice_account);
 
 		let expected_usable_amount;
@@ -76,6 +78,7 @@ fn insufficient_balance() {
 		assert_err!(
 			AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::signed(AirdropModule::get_airdrop_server_account().unwrap()),
+				0,
 				case.icon_address,
 				case.ice_address.clone(),
 				case.message,
@@ -83,7 +86,8 @@ fn insufficient_balance() {
 				case.ice_signature,
 				case.amount,
 				case.defi_user,
-				case.merkle_proofs
+				case.merkle_proofs,
+				case.expiry
 			),
 			PalletError::InsufficientCreditorBalance
 		);
@@ -107,7 +111,7 @@ fn already_claimed() {
 		snapshot.done_instant = true;
 		snapshot.done_vesting = true;
 
-		pallet_airdrop::IconSnapshotMap::<Test>::insert(&case.icon_address, snapshot);
+		pallet_airdrop::IconSnapshotMap::<Test>::insert(0, &case.icon_address, snapshot);
 		let creditor_account = force_get_creditor_account::<Test>();
 
 		<Test as Config>::Currency::set_balance(
@@ -121,6 +125,7 @@ fn already_claimed() {
 		assert_err!(
 			AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::signed(AirdropModule::get_airdrop_server_account().unwrap()),
+				0,
 				case.icon_address,
 				case.ice_address.clone(),
 				case.message,
@@ -128,7 +133,8 @@ fn already_claimed() {
 				case.ice_signature,
 				case.amount,
 				case.defi_user,
-				case.merkle_proofs
+				case.merkle_proofs,
+				case.expiry
 			),
 			PalletError::ClaimAlreadyMade
 		);
@@ -146,7 +152,7 @@ fn invalid_payload() {
 		));
 		let mut case = UserClaimTestCase::default();
 
-		case.message = *b"icx_sendTransaction.data.{method.transfer.params.{wallet.eee7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.version.0x3";
+		case.message = to_payload(b"icx_sendTransaction.data.{method.transfer.params.{wallet.eee7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.version.0x3");
 		let creditor_account = force_get_creditor_account::<Test>();
 
 		<Test as Config>::Currency::set_balance(
@@ -160,6 +166,7 @@ fn invalid_payload() {
 		assert_err!(
 			AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::signed(AirdropModule::get_airdrop_server_account().unwrap()),
+				0,
 				case.icon_address,
 				case.ice_address.clone(),
 				case.message,
@@ -167,7 +174,8 @@ fn invalid_payload() {
 				case.ice_signature,
 				case.amount,
 				case.defi_user,
-				case.merkle_proofs
+				case.merkle_proofs,
+				case.expiry
 			),
 			PalletError::InvalidMessagePayload
 		);
@@ -184,7 +192,7 @@ fn invalid_ice_signature() {
 			ofw_account
 		));
 		let mut case = UserClaimTestCase::default();
-		case.ice_signature = [0u8; 64];
+		case.ice_signature = sr25519::Signature::from_raw([0u8; 64]).into();
 
 		let creditor_account = force_get_creditor_account::<Test>();
 		<Test as Config>::Currency::set_balance(
@@ -198,6 +206,7 @@ fn invalid_ice_signature() {
 		assert_err!(
 			AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::signed(AirdropModule::get_airdrop_server_account().unwrap()),
+				0,
 				case.icon_address,
 				case.ice_address.clone(),
 				case.message,
@@ -205,7 +214,8 @@ fn invalid_ice_signature() {
 				case.ice_signature,
 				case.amount,
 				case.defi_user,
-				case.merkle_proofs
+				case.merkle_proofs,
+				case.expiry
 			),
 			PalletError::InvalidIceSignature
 		);
@@ -236,6 +246,7 @@ fn invalid_icon_signature() {
 		assert_err!(
 			AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::signed(AirdropModule::get_airdrop_server_account().unwrap()),
+				0,
 				case.icon_address,
 				case.ice_address.clone(),
 				case.message,
@@ -243,7 +254,8 @@ fn invalid_icon_signature() {
 				case.ice_signature,
 				case.amount,
 				case.defi_user,
-				case.merkle_proofs
+				case.merkle_proofs,
+				case.expiry
 			),
 			PalletError::InvalidSignature
 		);
@@ -264,7 +276,8 @@ fn respect_vesting_pallet_min_transfer() {
 		let mut snapshot =
 			types::SnapshotInfo::<Test>::new(ice_address, is_defi_user, total_amount);
 
-		let transfer_res = transfer::do_transfer::<Test>(&mut snapshot);
+		let campaign = AirdropModule::get_campaign(0).unwrap();
+		let transfer_res = transfer::do_transfer::<Test>(&mut snapshot, &campaign);
 
 		assert_ok!(transfer_res);
 		assert!(snapshot.done_vesting);
@@ -284,7 +297,7 @@ fn respect_vesting_pallet_min_transfer() {
 
 #[test]
 fn partial_transfer_can_reclaim() {
-	let vesting_period = Test::VESTING_TERMS.vesting_period;
+	let vesting_period = mock::VESTING_TERMS.vesting_period;
 	minimal_test_ext().execute_with(|| {
 		run_to_block(1);
 
@@ -298,7 +311,7 @@ fn partial_transfer_can_reclaim() {
 
 		let (init_instant_amount, init_vesting_amount) = utils::get_split_amounts::<Test>(
 			case.amount,
-			utils::get_instant_percentage::<Test>(case.defi_user),
+			utils::get_instant_percentage(&mock::VESTING_TERMS, case.defi_user),
 		)
 		.unwrap();
 		let (vesting_schedule, reminding_amount) = utils::new_vesting_with_deadline::<
@@ -334,6 +347,7 @@ fn partial_transfer_can_reclaim() {
 			let case = case.clone();
 			assert_ok!(AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::root(),
+				0,
 				case.icon_address,
 				case.ice_address,
 				case.message,
@@ -342,9 +356,10 @@ fn partial_transfer_can_reclaim() {
 				case.amount,
 				case.defi_user,
 				case.merkle_proofs,
+				case.expiry,
 			));
 
-			let snapshot = AirdropModule::get_icon_snapshot_map(&case.icon_address).unwrap();
+			let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
 			let mapped_icon_wallet = AirdropModule::get_ice_to_icon_map(&ice_account);
 			let new_balance = <Test as Config>::Currency::total_balance(&ice_account);
 			assert!(snapshot.done_instant);
@@ -354,6 +369,35 @@ fn partial_transfer_can_reclaim() {
 			let expected_vesting_status = cfg!(feature = "no-vesting");
 			assert_eq!(snapshot.done_vesting, expected_vesting_status);
 
+			// Failed vesting is reported before the partial claim
+			if !cfg!(feature = "no-vesting") {
+				let mut events = get_pallet_events();
+				let partial_claim = events.pop();
+				let vesting_failed = events.pop();
+				assert_eq!(
+					vesting_failed,
+					Some(PalletEvent::VestingFailed {
+						campaign_id: 0,
+						icon_address: case.icon_address,
+						claimer: ice_account.clone(),
+						vesting_schedule: vesting_schedule.unwrap(),
+						error: pallet_vesting::Error::<Test>::AtMaxVestingSchedules.into(),
+					})
+				);
+				assert_eq!(
+					partial_claim,
+					Some(PalletEvent::PartialClaim {
+						campaign_id: 0,
+						icon_address: case.icon_address,
+						claimer: ice_account.clone(),
+						instant_amount,
+						block_number: 1,
+						skipped_phase: None,
+						error: pallet_vesting::Error::<Test>::AtMaxVestingSchedules.into(),
+					})
+				);
+			}
+
 			user_balance = new_balance;
 		}
 
@@ -367,6 +411,7 @@ fn partial_transfer_can_reclaim() {
 
 		let reclaim_res = AirdropModule::dispatch_user_claim(
 			RuntimeOrigin::root(),
+			0,
 			case.icon_address,
 			case.ice_address,
 			case.message,
@@ -375,6 +420,7 @@ fn partial_transfer_can_reclaim() {
 			case.amount,
 			case.defi_user,
 			case.merkle_proofs,
+			case.expiry,
 		)
 		.map(|_| ());
 		let expected_res;
@@ -389,7 +435,7 @@ fn partial_transfer_can_reclaim() {
 			expected_vesting_block_number = Some(12);
 		};
 
-		let snapshot = AirdropModule::get_icon_snapshot_map(&case.icon_address).unwrap();
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
 		let mapped_icon_wallet = AirdropModule::get_ice_to_icon_map(&ice_account);
 		let final_balance = <Test as Config>::Currency::total_balance(&ice_account);
 		assert_eq!(reclaim_res, expected_res);
@@ -399,5 +445,216 @@ fn partial_transfer_can_reclaim() {
 		assert_eq!(expected_instant_block_number, snapshot.instant_block_number);
 		assert_eq!(expected_vesting_block_number, snapshot.vesting_block_number);
 		assert_eq!(expected_final_balance, final_balance);
+
+		// Reclaim only applies vesting and skips instant transfer
+		if !cfg!(feature = "no-vesting") {
+			assert_eq!(
+				get_last_event(),
+				Some(
+					PalletEvent::ClaimSuccess {
+						campaign_id: 0,
+						icon_address: case.icon_address,
+						claimer: ice_account,
+						instant_amount: 0,
+						vested_amount: vesting_amount,
+						vesting_schedule,
+						block_number: 12,
+						skipped_phase: Some(types::ClaimPhase::Instant),
+					}
+					.into()
+				)
+			);
+		}
+	});
+}
+
+#[test]
+fn batch_claim_reports_each_claim() {
+	minimal_test_ext().execute_with(|| {
+		run_to_block(1);
+		set_creditor_balance(10_000_0000);
+
+		let valid_case = UserClaimTestCase::default();
+		let mut invalid_case = UserClaimTestCase::default();
+		invalid_case.icon_address = samples::ICON_ADDRESS[0];
+
+		let to_request = |case: UserClaimTestCase| types::UserClaimRequest::<Test> {
+			campaign_id: 0,
+			icon_address: case.icon_address,
+			ice_address: case.ice_address,
+			message: case.message,
+			icon_signature: case.icon_signature,
+			ice_signature: case.ice_signature,
+			amount: case.amount,
+			defi_user: case.defi_user,
+			proofs: case.merkle_proofs,
+			expiry: case.expiry,
+		};
+		let claims: types::UserClaimBatch<Test> = vec![
+			to_request(valid_case.clone()),
+			to_request(invalid_case.clone()),
+		]
+		.try_into()
+		.unwrap();
+
+		assert_ok!(AirdropModule::dispatch_user_claims_batch(
+			RuntimeOrigin::root(),
+			claims
+		));
+
+		let events = get_pallet_events();
+		assert!(events.iter().any(|event| matches!(
+			event,
+			PalletEvent::ClaimSuccess { icon_address, .. } if *icon_address == valid_case.icon_address
+		)));
+		assert!(events.contains(&PalletEvent::ClaimFailed {
+			campaign_id: 0,
+			icon_address: invalid_case.icon_address,
+			error: PalletError::InvalidSignature.into(),
+		}));
+		assert_eq!(
+			get_last_event(),
+			Some(
+				PalletEvent::ClaimBatchProcessed {
+					succeeded: 1,
+					failed: 1
+				}
+				.into()
+			)
+		);
+
+		// Only valid claim should have been recorded
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &valid_case.icon_address).unwrap();
+		assert!(snapshot.done_instant);
+		assert_eq!(
+			AirdropModule::get_icon_snapshot_map(0, &invalid_case.icon_address),
+			None
+		);
+	});
+}
+
+#[test]
+fn batch_claim_denied_for_non_server() {
+	minimal_test_ext().execute_with(|| {
+		let claims: types::UserClaimBatch<Test> = Default::default();
+
+		assert_noop!(
+			AirdropModule::dispatch_user_claims_batch(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[1]),
+				claims
+			),
+			PalletError::DeniedOperation
+		);
+	});
+}
+
+#[test]
+fn unsigned_claim_success() {
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+
+		assert_ok!(AirdropModule::claim_unsigned(
+			RuntimeOrigin::none(),
+			0,
+			case.icon_address,
+			case.ice_address,
+			case.message,
+			case.icon_signature,
+			case.ice_signature.clone(),
+			case.amount,
+			case.defi_user,
+			case.merkle_proofs.clone(),
+			case.expiry,
+		));
+
+		let snapshot = AirdropModule::get_icon_snapshot_map(0, &case.icon_address).unwrap();
+		assert!(snapshot.done_instant);
+		assert!(snapshot.done_vesting);
+
+		// Only unsigned origin is allowed
+		assert_noop!(
+			AirdropModule::claim_unsigned(
+				RuntimeOrigin::signed(samples::ACCOUNT_ID[1]),
+				0,
+				case.icon_address,
+				case.ice_address,
+				case.message,
+				case.icon_signature,
+				case.ice_signature,
+				case.amount,
+				case.defi_user,
+				case.merkle_proofs,
+				case.expiry,
+			),
+			PalletError::DeniedOperation
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_claim() {
+	use frame_support::pallet_prelude::{
+		InvalidTransaction, TransactionSource, ValidTransaction, ValidateUnsigned,
+	};
+
+	let to_call = |case: UserClaimTestCase| PalletCall::claim_unsigned {
+		campaign_id: 0,
+		icon_address: case.icon_address,
+		ice_address: case.ice_address,
+		message: case.message,
+		icon_signature: case.icon_signature,
+		ice_signature: case.ice_signature,
+		total_amount: case.amount,
+		defi_user: case.defi_user,
+		proofs: case.merkle_proofs,
+		expiry: case.expiry,
+	};
+
+	minimal_test_ext().execute_with(|| {
+		set_creditor_balance(10_000_0000);
+		let case = UserClaimTestCase::default();
+
+		// Valid claim is accepted with tags derived from claim
+		assert_eq!(
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(case.clone())),
+			Ok(ValidTransaction {
+				priority: 100,
+				requires: vec![],
+				provides: vec![
+					("AirdropClaim", (0_u32, case.icon_address)).encode(),
+					("AirdropClaim", (0_u32, case.ice_address)).encode()
+				],
+				longevity: 64,
+				propagate: true,
+			})
+		);
+
+		// Invalid signature is rejected before reaching the block
+		let mut invalid_case = case.clone();
+		invalid_case.ice_signature = sr25519::Signature::from_raw([0u8; 64]).into();
+		assert_eq!(
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(invalid_case)),
+			Err(InvalidTransaction::BadProof.into())
+		);
+
+		// Claim that creditor cannot pay out is not accepted
+		set_creditor_balance(case.amount as u64);
+		assert_eq!(
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(case.clone())),
+			Err(InvalidTransaction::Payment.into())
+		);
+		set_creditor_balance(10_000_0000);
+
+		// Already claimed snapshot is stale
+		let ice_account = AirdropModule::convert_to_account_id(case.ice_address).unwrap();
+		let mut snapshot = types::SnapshotInfo::default().ice_address(ice_account);
+		snapshot.done_instant = true;
+		snapshot.done_vesting = true;
+		pallet_airdrop::IconSnapshotMap::<Test>::insert(0, &case.icon_address, snapshot);
+		assert_eq!(
+			AirdropModule::validate_unsigned(TransactionSource::External, &to_call(case)),
+			Err(InvalidTransaction::Stale.into())
+		);
 	});
 }
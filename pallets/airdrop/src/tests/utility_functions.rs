@@ -71,8 +71,9 @@ fn get_vesting_amounts_split() {
 	let expected_non_defi_instant_per = 30;
 	minimal_test_ext().execute_with(|| {
 		let get_split_amounts: _ = utils::get_split_amounts::<Test>;
-		let defi_instant = utils::get_instant_percentage::<Test>(true);
-		let non_defi_instant = utils::get_instant_percentage::<Test>(false);
+		let terms = AirdropModule::get_campaign(0).unwrap().vesting_terms;
+		let defi_instant = utils::get_instant_percentage(&terms, true);
+		let non_defi_instant = utils::get_instant_percentage(&terms, false);
 
 		assert_eq!(
 			(expected_defi_instant_per, expected_non_defi_instant_per),
@@ -221,7 +222,7 @@ fn cook_vesting_schedule() {
 
 #[test]
 fn making_vesting_transfer() {
-	let get_per: _ = utils::get_instant_percentage::<Test>;
+	let get_per = |is_defi_user| utils::get_instant_percentage(&mock::VESTING_TERMS, is_defi_user);
 
 	minimal_test_ext().execute_with(|| {
 		run_to_block(3);
@@ -243,7 +244,7 @@ fn making_vesting_transfer() {
 				..Default::default()
 			};
 
-			assert_ok!(AirdropModule::do_transfer(&mut snapshot, &icon_address));
+			assert_ok!(AirdropModule::do_transfer(0, &mut snapshot, &icon_address));
 
 			// Ensure all amount is being transferred
 			assert_eq!(9775129_u128, Currency::free_balance(&claimer));
@@ -269,7 +270,7 @@ fn making_vesting_transfer() {
 				..Default::default()
 			};
 
-			assert_ok!(AirdropModule::do_transfer(&mut snapshot, &icon_address));
+			assert_ok!(AirdropModule::do_transfer(0, &mut snapshot, &icon_address));
 
 			// Ensure amount only accounting to vesting is transferred
 
@@ -306,7 +307,7 @@ fn making_vesting_transfer() {
 				..Default::default()
 			};
 
-			assert_ok!(AirdropModule::do_transfer(&mut snapshot, &icon_address));
+			assert_ok!(AirdropModule::do_transfer(0, &mut snapshot, &icon_address));
 
 			// Ensure amount only accounting to instant is transferred
 			let expected_transfer = {
@@ -341,7 +342,7 @@ fn making_vesting_transfer() {
 				..Default::default()
 			};
 
-			assert_ok!(AirdropModule::do_transfer(&mut snapshot, &icon_address));
+			assert_ok!(AirdropModule::do_transfer(0, &mut snapshot, &icon_address));
 
 			// Ensure amount only accounting to instant is transferred
 			assert_eq!(0_u128, Currency::free_balance(&claimer));
@@ -353,12 +354,24 @@ fn making_vesting_transfer() {
 }
 
 #[test]
-fn test_extract_address() {
-	let payload = "icx_sendTransaction.data.{method.transfer.params.{wallet.b6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.version.0x3".as_bytes();
-	let expected_address =
-		hex_literal::hex!("b6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48");
-	let extracted_address = utils::extract_ice_address(payload, &expected_address).unwrap();
-	assert_eq!(extracted_address, expected_address);
+fn icon_verifier_extract_destination() {
+	use pallet_airdrop::types::SourceChainVerifier;
+	type IconVerifier = pallet_airdrop::icon::IconVerifier<frame_support::traits::ConstU64<1>>;
+
+	let payload = samples::VALID_MESSAGE;
+	assert_eq!(
+		IconVerifier::extract_destination(payload, 32),
+		Some(samples::VALID_ICE_ADDRESS.to_vec())
+	);
+
+	// Truncated payload is not a transaction at all
+	assert_eq!(IconVerifier::extract_destination(&payload[..80], 32), None);
+
+	// Recovered signer is the icon address that signed payload
+	assert_eq!(
+		IconVerifier::recover_signer(&samples::VALID_ICON_SIGNATURE, payload),
+		Ok(samples::VALID_ICON_WALLET)
+	);
 }
 
 #[test]
@@ -396,11 +409,13 @@ fn respect_airdrop_state() {
 		assert_err!(
 			AirdropModule::dispatch_user_claim(
 				RuntimeOrigin::root(),
+				0,
+				Default::default(),
 				Default::default(),
 				Default::default(),
-				[0; 289],
 				[0; 65],
-				[0; 64],
+				sr25519::Signature::from_raw([0; 64]).into(),
+				Default::default(),
 				Default::default(),
 				Default::default(),
 				Default::default(),
@@ -433,6 +448,7 @@ fn respect_airdrop_state() {
 		assert_noop!(
 			AirdropModule::dispatch_exchange_claim(
 				RuntimeOrigin::root(),
+				0,
 				Default::default(),
 				Default::default(),
 				Default::default(),
@@ -457,7 +473,7 @@ fn validate_creditor_fund() {
 		// When creditor balance is empty.
 		{
 			assert_err!(
-				AirdropModule::validate_creditor_fund(10),
+				AirdropModule::validate_creditor_fund(0, 10),
 				PalletError::InsufficientCreditorBalance
 			);
 		}
@@ -466,7 +482,7 @@ fn validate_creditor_fund() {
 		{
 			transfer_to_creditor(&donor, existential_balance);
 			assert_err!(
-				AirdropModule::validate_creditor_fund(existential_balance.try_into().unwrap()),
+				AirdropModule::validate_creditor_fund(0, existential_balance.try_into().unwrap()),
 				PalletError::InsufficientCreditorBalance,
 			);
 		}
@@ -479,14 +495,14 @@ fn validate_creditor_fund() {
 			);
 
 			assert_err!(
-				AirdropModule::validate_creditor_fund(required_balance.try_into().unwrap()),
+				AirdropModule::validate_creditor_fund(0, required_balance.try_into().unwrap()),
 				PalletError::InsufficientCreditorBalance,
 			);
 		}
 
 		// When only a portion of balance is required
 		{
-			assert_ok!(AirdropModule::validate_creditor_fund(10_000_000),);
+			assert_ok!(AirdropModule::validate_creditor_fund(0, 10_000_000),);
 		}
 	});
 }
@@ -542,6 +558,7 @@ fn insert_or_get_snapshot() {
 
 		assert_noop!(
 			AirdropModule::insert_or_get_snapshot(
+				0,
 				&icon_address_two,
 				&ice_address.encode().try_into().unwrap(),
 				false,
@@ -560,10 +577,11 @@ fn insert_or_get_snapshot() {
 		assert_ne!(ice_address_one, ice_address_two);
 
 		let snapshot = types::SnapshotInfo::<Test>::default().ice_address(ice_address_one.clone());
-		<IconSnapshotMap<Test>>::insert(&icon_address, snapshot);
+		<IconSnapshotMap<Test>>::insert(0, &icon_address, snapshot);
 
 		assert_noop!(
 			AirdropModule::insert_or_get_snapshot(
+				0,
 				&icon_address,
 				&ice_address_two.encode().try_into().unwrap(),
 				false,
@@ -580,11 +598,12 @@ fn insert_or_get_snapshot() {
 		let ice_address = samples::ACCOUNT_ID[1];
 
 		let snapshot = types::SnapshotInfo::<Test>::default().ice_address(ice_address.clone());
-		<IconSnapshotMap<Test>>::insert(&icon_address, snapshot.clone());
+		<IconSnapshotMap<Test>>::insert(0, &icon_address, snapshot.clone());
 		<IceIconMap<Test>>::insert(&ice_address, &icon_address);
 
 		let expected_snapshot = snapshot;
 		let call_result = AirdropModule::insert_or_get_snapshot(
+			0,
 			&icon_address,
 			&ice_address.encode().try_into().unwrap(),
 			false,
@@ -593,7 +612,7 @@ fn insert_or_get_snapshot() {
 
 		assert_eq!(call_result.as_ref(), Ok(&expected_snapshot));
 		assert_eq!(
-			AirdropModule::get_icon_snapshot_map(&icon_address),
+			AirdropModule::get_icon_snapshot_map(0, &icon_address),
 			Some(expected_snapshot)
 		);
 		assert_eq!(
@@ -610,6 +629,7 @@ fn insert_or_get_snapshot() {
 		let expected_snapshot =
 			types::SnapshotInfo::<Test>::default().ice_address(ice_address.clone());
 		let call_result = AirdropModule::insert_or_get_snapshot(
+			0,
 			&icon_address,
 			&ice_address.encode().try_into().unwrap(),
 			false,
@@ -618,7 +638,7 @@ fn insert_or_get_snapshot() {
 
 		assert_eq!(call_result.as_ref(), Ok(&expected_snapshot));
 		assert_eq!(
-			AirdropModule::get_icon_snapshot_map(&icon_address),
+			AirdropModule::get_icon_snapshot_map(0, &icon_address),
 			Some(expected_snapshot)
 		);
 		assert_eq!(
@@ -631,7 +651,11 @@ fn insert_or_get_snapshot() {
 #[test]
 fn storage_version() {
 	minimal_test_ext().execute_with(|| {
-		let version = AirdropModule::get_storage_version();
-		assert_eq!(1_u32, version);
+		use frame_support::traits::GetStorageVersion;
+
+		assert_eq!(
+			AirdropModule::on_chain_storage_version(),
+			pallet_airdrop::STORAGE_VERSION
+		);
 	});
 }
@@ -1,52 +1,99 @@
 use crate as airdrop;
 use airdrop::{error, info};
-use airdrop::{types, utils, Pallet as AirdropModule};
+use airdrop::{types, utils};
 use frame_support::pallet_prelude::*;
 use frame_support::traits::{Currency, ExistenceRequirement};
-use sp_runtime::traits::{CheckedAdd, Convert};
+use sp_runtime::traits::{CheckedAdd, Convert, Zero};
 
 // Block number after which enable to do vesting
 pub const VESTING_APPLICABLE_FROM: u32 = 1u32;
 
-pub fn do_transfer<T: airdrop::Config>(snapshot: &mut types::SnapshotInfo<T>) -> DispatchResult {
-	let vesting_should_end_in = <T as airdrop::Config>::VESTING_TERMS.vesting_period;
-	let creditor = AirdropModule::<T>::get_creditor_account()?;
-
-	let defi_user = snapshot.defi_user;
-	let total_amount = snapshot.amount;
-	let claimer = &snapshot.ice_address;
-	let instant_percentage = utils::get_instant_percentage::<T>(defi_user);
-
-	let (mut instant_amount, vesting_amount) =
+/// Split given amount as per vesting terms.
+/// Returns instant amount, vesting schedule if any and the remainder which
+/// could not be vested. Remainder is expected to be transferred instantly
+pub fn split_amount<T: airdrop::Config>(
+	total_amount: types::BalanceOf<T>,
+	defi_user: bool,
+	vesting_terms: &types::VestingTerms,
+) -> Result<
+	(
+		types::BalanceOf<T>,
+		Option<types::VestingInfoOf<T>>,
+		types::BalanceOf<T>,
+	),
+	DispatchError,
+> {
+	let instant_percentage = utils::get_instant_percentage(vesting_terms, defi_user);
+
+	let (instant_amount, vesting_amount) =
 			utils::get_split_amounts::<T>(total_amount, instant_percentage).map_err(|e |{
 				error!("At: get_split_amount. amount: {total_amount:?}. Instant percentage: {instant_percentage}. Reason: {e:?}");
 				e
 			})?;
 
-	let (transfer_schedule, remaining_amount) = utils::new_vesting_with_deadline::<
-		T,
-		VESTING_APPLICABLE_FROM,
-	>(vesting_amount, vesting_should_end_in.into());
+	let (schedule, remaining_amount) = utils::new_vesting_with_deadline::<T, VESTING_APPLICABLE_FROM>(
+		vesting_amount,
+		vesting_terms.vesting_period.into(),
+	);
+
+	let remaining_amount = <T::BalanceTypeConversion as Convert<
+		types::VestingBalanceOf<T>,
+		types::BalanceOf<T>,
+	>>::convert(remaining_amount);
+
+	Ok((instant_amount, schedule, remaining_amount))
+}
+
+/// Outcome of a single call to `do_transfer`
+#[derive(RuntimeDebugNoBound)]
+pub struct TransferOutcome<T: airdrop::Config> {
+	/// Amount transferred instantly in this call
+	pub instant_amount: types::BalanceOf<T>,
+
+	/// Vesting schedule applied or attempted in this call
+	pub vesting_schedule: Option<types::VestingInfoOf<T>>,
+
+	/// Phase that was not repeated as it was completed before
+	pub skipped_phase: Option<types::ClaimPhase>,
+
+	/// Reason vesting could not be applied if it failed
+	pub vesting_error: Option<DispatchError>,
+}
+
+pub fn do_transfer<T: airdrop::Config>(
+	snapshot: &mut types::SnapshotInfo<T>,
+	campaign: &types::CampaignInfo<T>,
+) -> Result<TransferOutcome<T>, DispatchError> {
+	// Once a split have been made for this snapshot, same terms are used
+	// in every reclaim even if campaign terms are updated in between
+	let vesting_terms = snapshot.vesting_terms.unwrap_or(campaign.vesting_terms);
+	snapshot.vesting_terms = Some(vesting_terms);
+
+	let creditor = campaign.creditor.clone();
+	let claimer = &snapshot.ice_address;
+
+	let (instant_amount, transfer_schedule, remaining_amount) =
+		split_amount::<T>(snapshot.amount, snapshot.defi_user, &vesting_terms)?;
 
 	// Amount to be transferred is:
 	// x% of total amount
 	// + remaining amount which was not perfectly divisible
-	instant_amount = {
-		let remaining_amount = <T::BalanceTypeConversion as Convert<
-			types::VestingBalanceOf<T>,
-			types::BalanceOf<T>,
-		>>::convert(remaining_amount);
-
-		instant_amount
-			.checked_add(&remaining_amount)
-			.ok_or(sp_runtime::ArithmeticError::Overflow)?
-	};
+	let instant_amount = instant_amount
+		.checked_add(&remaining_amount)
+		.ok_or(sp_runtime::ArithmeticError::Overflow)?;
 
 	let creditor_origin = <T as frame_system::Config>::RuntimeOrigin::from(
 		frame_system::RawOrigin::Signed(creditor.clone()),
 	);
 	let claimer_origin = <T::Lookup as sp_runtime::traits::StaticLookup>::unlookup(claimer.clone());
 
+	let mut outcome = TransferOutcome::<T> {
+		instant_amount: Zero::zero(),
+		vesting_schedule: None,
+		skipped_phase: None,
+		vesting_error: None,
+	};
+
 	match transfer_schedule {
 		// Apply vesting
 		Some(schedule) if !snapshot.done_vesting => {
@@ -56,6 +103,7 @@ pub fn do_transfer<T: airdrop::Config>(snapshot: &mut types::SnapshotInfo<T>) ->
 				schedule,
 			);
 
+			outcome.vesting_schedule = Some(schedule);
 			match vest_res {
 				// Everything went ok. update flag
 				Ok(()) => {
@@ -65,9 +113,10 @@ pub fn do_transfer<T: airdrop::Config>(snapshot: &mut types::SnapshotInfo<T>) ->
 
 					info!("Vesting applied for {claimer:?} at height {block_number:?}");
 				}
-				// log error
+				// Instant transfer is still made, report the error to caller
 				Err(err) => {
 					error!("Error while applying vesting. For: {claimer:?}. Reason: {err:?}");
+					outcome.vesting_error = Some(err);
 				}
 			}
 		}
@@ -78,6 +127,7 @@ pub fn do_transfer<T: airdrop::Config>(snapshot: &mut types::SnapshotInfo<T>) ->
 				"Skipped vesting for: {claimer:?}. Reason: {reason}",
 				reason = "snapshot.done_vesting was already true"
 			);
+			outcome.skipped_phase = Some(types::ClaimPhase::Vesting);
 		}
 
 		// No schedule was created
@@ -108,12 +158,14 @@ pub fn do_transfer<T: airdrop::Config>(snapshot: &mut types::SnapshotInfo<T>) ->
 		snapshot.done_instant = true;
 		snapshot.initial_transfer = instant_amount;
 		snapshot.instant_block_number = Some(utils::get_current_block_number::<T>());
+		outcome.instant_amount = instant_amount;
 	} else {
 		info!(
 			"skipped instant transfer for {claimer:?}. Reason: {reason}",
 			reason = "snapshot.done_instant was set to true already"
 		);
+		outcome.skipped_phase = Some(types::ClaimPhase::Instant);
 	}
 
-	Ok(())
+	Ok(outcome)
 }
@@ -31,6 +31,17 @@ pub fn to_balance<T: Config>(amount: ServerBalance) -> BalanceOf<T> {
 	<T::BalanceTypeConversion as Convert<ServerBalance, BalanceOf<T>>>::convert(amount)
 }
 
+/// Same as `to_balance` but fails if amount do not survive the round trip
+/// i.e it was truncated or saturated by `BalanceTypeConversion`
+pub fn try_to_balance<T: Config>(amount: ServerBalance) -> Result<BalanceOf<T>, Error<T>> {
+	let balance = to_balance::<T>(amount);
+	if from_balance::<T>(balance) == amount {
+		Ok(balance)
+	} else {
+		Err(Error::<T>::FailedConversion)
+	}
+}
+
 pub fn from_balance<T: Config>(amount: BalanceOf<T>) -> ServerBalance {
 	<T::BalanceTypeConversion as Convert<BalanceOf<T>, ServerBalance>>::convert(amount)
 }
@@ -42,22 +53,52 @@ pub type IceAddress = [u8; 32];
 
 pub type IceEvmAddress = H160;
 
+/// Ethereum style recoverable ECDSA signature made by ice evm address
+pub type IceEvmSignature = [u8; 65];
+
 /// Type that represent Icon signed message
 pub type IconSignature = [u8; 65];
 
-//
-pub type IceSignature = [u8; 64];
+/// Signature made by ice address over icon signature.
+/// Any of sr25519, ed25519 or ecdsa scheme is accepted
+pub type IceSignature = sp_runtime::MultiSignature;
+
+/// Message signed by source address that embed ice address
+pub type RawPayload<T> = <<T as Config>::SourceChainVerifier as SourceChainVerifier>::Payload;
 
-//
-pub type RawPayload = [u8; RAW_PAYLOAD_LENGTH];
+/// Message signed by source address that embed ice evm address
+pub type EvmRawPayload<T> = <<T as Config>::SourceChainVerifier as SourceChainVerifier>::EvmPayload;
 
 ///
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 
 pub type MerkleHash = [u8; 32];
+
+/// Hash under which consumed signed message is recorded
+pub type PayloadHash = [u8; 32];
 // pub type MerkleProofs=Vec<MerkleHash>;
 pub type MerkleProofs<T> = BoundedVec<MerkleHash, <T as Config>::MaxProofSize>;
 
+/// Bounded list of user claims to be processed in single call
+pub type UserClaimBatch<T> = BoundedVec<UserClaimRequest<T>, <T as Config>::MaxClaimBatchSize>;
+
+/// Claim preview as returned by runtime api
+pub type ClaimPreviewOf<T> =
+	pallet_airdrop_runtime_api::ClaimPreview<AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
+/// Snapshot as returned by runtime api
+pub type SnapshotPreviewOf<T> =
+	pallet_airdrop_runtime_api::SnapshotPreview<AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
+/// Partially claimed snapshot as returned by runtime api
+pub type PartialSnapshotOf<T> =
+	pallet_airdrop_runtime_api::PartialSnapshot<AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+pub type PartialSnapshotsPageOf<T> = pallet_airdrop_runtime_api::PartialSnapshotsPage<
+	AccountIdOf<T>,
+	BalanceOf<T>,
+	BlockNumberOf<T>,
+>;
+
 ///
 pub type VestingInfoOf<T> = pallet_vesting::VestingInfo<VestingBalanceOf<T>, BlockNumberOf<T>>;
 
@@ -68,6 +109,12 @@ pub enum SignatureValidationError {
 	InvalidIconSignature,
 	InvalidIceAddress,
 	Sha3Execution,
+	/// Signed message is not in the format source chain expect
+	MalformedPayload,
+	/// Signed message is meant for other network
+	NetworkMismatch,
+	/// Sender written in message is not the signer
+	SenderMismatch,
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
@@ -98,6 +145,31 @@ pub struct SnapshotInfo<T: Config> {
 	pub instant_block_number: Option<BlockNumberOf<T>>,
 
 	pub initial_transfer: BalanceOf<T>,
+
+	/// Vesting terms that were used to split the amount.
+	/// None until first transfer is made
+	pub vesting_terms: Option<VestingTerms>,
+}
+
+/// All the information server provide for a single user claim
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct UserClaimRequest<T: Config> {
+	pub campaign_id: CampaignId,
+	pub icon_address: IconAddress,
+	pub ice_address: IceAddress,
+	pub message: RawPayload<T>,
+	pub icon_signature: IconSignature,
+	pub ice_signature: IceSignature,
+	pub amount: BalanceOf<T>,
+	pub defi_user: bool,
+	pub proofs: MerkleProofs<T>,
+	/// Last block in which this claim can be made. Advisory only: it is
+	/// chosen by submitter and not covered by any signature so it can
+	/// shorten but never extend how long signed message is accepted
+	pub expiry: BlockNumberOf<T>,
 }
 
 impl<T: Config> core::fmt::Debug for SnapshotInfo<T> {
@@ -111,6 +183,7 @@ impl<T: Config> core::fmt::Debug for SnapshotInfo<T> {
 			.field("vesting_block_number", &self.vesting_block_number)
 			.field("initial_transfer", &self.initial_transfer)
 			.field("instant_block_number", &self.instant_block_number)
+			.field("vesting_terms", &self.vesting_terms)
 			.finish()
 	}
 }
@@ -126,6 +199,30 @@ impl<T: Config> SnapshotInfo<T> {
 			vesting_block_number: None,
 			instant_block_number: None,
 			initial_transfer: 0u32.into(),
+			vesting_terms: None,
+		}
+	}
+
+	/// Instant transfer have been made but vesting is yet to be applied
+	pub fn is_partial(&self) -> bool {
+		self.done_instant && !self.done_vesting
+	}
+
+	/// Representation of this snapshot exposed through runtime api
+	pub fn to_preview(&self) -> SnapshotPreviewOf<T> {
+		let vesting_terms = self.vesting_terms.as_ref();
+
+		pallet_airdrop_runtime_api::SnapshotPreview {
+			ice_address: self.ice_address.clone(),
+			amount: self.amount,
+			defi_user: self.defi_user,
+			done_instant: self.done_instant,
+			done_vesting: self.done_vesting,
+			vesting_block_number: self.vesting_block_number,
+			instant_block_number: self.instant_block_number,
+			initial_transfer: self.initial_transfer,
+			instant_percentage: vesting_terms.map(|terms| terms.instant_percentage(self.defi_user)),
+			vesting_period: vesting_terms.map(|terms| terms.vesting_period),
 		}
 	}
 }
@@ -137,18 +234,15 @@ impl<T: Config> From<ArithmeticError> for Error<T> {
 }
 
 impl<T: Config> From<SignatureValidationError> for Error<T> {
-	fn from(_: SignatureValidationError) -> Self {
-		Error::<T>::InvalidSignature
+	fn from(error: SignatureValidationError) -> Self {
+		match error {
+			SignatureValidationError::MalformedPayload => Error::<T>::InvalidMessagePayload,
+			SignatureValidationError::NetworkMismatch => Error::<T>::InvalidNetworkId,
+			_ => Error::<T>::InvalidSignature,
+		}
 	}
 }
 
-pub fn balance_to_u32<T: Config>(input: BalanceOf<T>) -> u32 {
-	TryInto::<u32>::try_into(input).ok().unwrap()
-}
-
-pub fn block_number_to_u32<T: Config>(input: BlockNumberOf<T>) -> u32 {
-	TryInto::<u32>::try_into(input).ok().unwrap()
-}
 /// Chain state
 #[derive(Deserialize, Encode, Decode, Clone, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
 #[cfg_attr(test, derive(serde::Serialize))]
@@ -182,10 +276,143 @@ pub trait MerkelProofValidator<T: Config> {
 	fn validate(leaf_hash: MerkleHash, root_hash: MerkleHash, proofs: MerkleProofs<T>) -> bool;
 }
 
+/// Encode a snapshot entry into the bytes that are hashed as merkle leaf
+pub trait MerkleLeafEncoder {
+	fn encode_leaf(icon_address: &IconAddress, amount: ServerBalance, defi_user: bool) -> Vec<u8>;
+}
+
+/// Hash function used for both leaf and inner nodes of merkle tree
+pub trait MerkleNodeHasher {
+	fn hash(data: &[u8]) -> MerkleHash;
+}
+
+/// Source chain specific part of a claim. Define the message that
+/// source address sign and how the signer is recovered from it
+pub trait SourceChainVerifier {
+	/// Signed message that embed ice address as destination
+	type Payload: Parameter + Member + AsRef<[u8]> + TryFrom<Vec<u8>>;
+
+	/// Signed message that embed ice evm address as destination
+	type EvmPayload: Parameter + Member + AsRef<[u8]> + TryFrom<Vec<u8>>;
+
+	/// Destination address of given length embedded in signed message
+	fn extract_destination(payload: &[u8], destination_len: usize) -> Option<Vec<u8>>;
+
+	/// Unix time in milliseconds at which message was signed
+	/// as written in the message itself. Zero when signer did not
+	/// record the time
+	fn signed_at(payload: &[u8]) -> Option<u64>;
+
+	/// Address in source chain that signed the message
+	fn recover_signer(
+		signature: &IconSignature,
+		payload: &[u8],
+	) -> Result<IconAddress, SignatureValidationError>;
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct VestingTerms {
 	pub defi_instant_percentage: u8,
 	pub non_defi_instant_percentage: u8,
 	pub vesting_period: u32,
 }
 
-pub const RAW_PAYLOAD_LENGTH: usize = b"icx_sendTransaction.data.{method.transfer.params.{wallet.b6e7a79d04e11a2dd43399f677878522523327cae2691b6cd1eb972b5a88eb48}}.dataType.call.from.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.nid.0x1.nonce.0x1.stepLimit.0x0.timestamp.0x0.to.hxb48f3bd3862d4a489fb3c9b761c4cfb20b34a645.version.0x3".len();
+impl VestingTerms {
+	/// Instant percentages cannot exceed 100
+	pub fn is_valid(&self) -> bool {
+		self.defi_instant_percentage <= 100 && self.non_defi_instant_percentage <= 100
+	}
+
+	pub fn instant_percentage(&self, is_defi_user: bool) -> u8 {
+		if is_defi_user {
+			self.defi_instant_percentage
+		} else {
+			self.non_defi_instant_percentage
+		}
+	}
+}
+
+/// Identifier of an airdrop round
+pub type CampaignId = u32;
+
+/// Phase of a claim. Each phase is completed only once
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum ClaimPhase {
+	/// Instant transfer of non vested amount
+	Instant,
+
+	/// Vested transfer of remaining amount
+	Vesting,
+}
+
+/// Whether a campaign is accepting claims
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum CampaignState {
+	Open,
+	Closed,
+	/// Deadline have passed and remaining fund have been swept.
+	/// Campaign cannot be reopened from this state
+	Swept,
+}
+
+/// A single airdrop round. Each round have it's own merkle tree,
+/// vesting terms and account from where fund is transferred
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebugNoBound)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct CampaignInfo<T: Config> {
+	/// Root of merkle tree this round's snapshot is built into
+	pub merkle_root: MerkleHash,
+
+	/// Instant/vesting split applied to claims of this round
+	pub vesting_terms: VestingTerms,
+
+	/// Account from where this round is funded
+	pub creditor: AccountIdOf<T>,
+
+	/// Whether claims are accepted at all
+	pub state: CampaignState,
+
+	/// First block from which claims are accepted
+	pub start_block: BlockNumberOf<T>,
+
+	/// Last block at which claims are accepted i.e claim deadline.
+	/// None means no deadline
+	pub end_block: Option<BlockNumberOf<T>>,
+}
+
+impl<T: Config> CampaignInfo<T> {
+	/// Campaign that is open from the very first block and never ends
+	pub fn new(
+		merkle_root: MerkleHash,
+		vesting_terms: VestingTerms,
+		creditor: AccountIdOf<T>,
+	) -> Self {
+		Self {
+			merkle_root,
+			vesting_terms,
+			creditor,
+			state: CampaignState::Open,
+			start_block: 0_u32.into(),
+			end_block: None,
+		}
+	}
+}
+
+/// Low water mark of a campaign creditor and allowance
+/// it can be refilled from
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebugNoBound)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct CreditorFundingInfo<T: Config> {
+	/// Creditor balance below which it is considered to be running low
+	pub low_water_mark: BalanceOf<T>,
+
+	/// Amount moved into creditor every time it falls below low water mark
+	pub refill_amount: BalanceOf<T>,
+
+	/// Total amount that is still approved to be moved into creditor.
+	/// Zero means creditor is never refilled and only alert is made
+	pub allowance: BalanceOf<T>,
+}
@@ -5,7 +5,9 @@ use frame_support::traits::Get;
 use hex::FromHexError;
 use sp_core::H160;
 use sp_runtime::{
-	traits::{BlakeTwo256, Bounded, CheckedDiv, CheckedMul, CheckedSub, Convert, Saturating},
+	traits::{
+		BlakeTwo256, Bounded, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Convert, Saturating,
+	},
 	AccountId32, DispatchError,
 };
 use sp_std::vec::Vec;
@@ -32,7 +34,10 @@ where
 
 	let idol_transfer_multiple = transfer_over * MIN_AMOUNT_PER_BLOCK.into();
 
-	let mut remaining_amount = amount % idol_transfer_multiple;
+	// Nothing can be vested over zero blocks
+	let mut remaining_amount = amount
+		.checked_rem(&idol_transfer_multiple)
+		.unwrap_or(amount);
 	let primary_transfer_amount = amount.saturating_sub(remaining_amount);
 
 	let per_block = primary_transfer_amount
@@ -55,12 +60,8 @@ where
 	(vesting, remaining_amount)
 }
 
-pub fn get_instant_percentage<T: airdrop::Config>(is_defi_user: bool) -> u8 {
-	if is_defi_user {
-		T::VESTING_TERMS.defi_instant_percentage
-	} else {
-		T::VESTING_TERMS.non_defi_instant_percentage
-	}
+pub fn get_instant_percentage(terms: &types::VestingTerms, is_defi_user: bool) -> u8 {
+	terms.instant_percentage(is_defi_user)
 }
 
 pub fn get_split_amounts<T: airdrop::Config>(
@@ -129,6 +130,8 @@ pub fn recover_address(
 	Ok(address)
 }
 
+/// Account that given evm address map into.
+/// Same as `pallet_evm::HashedAddressMapping<BlakeTwo256>` used by runtime
 pub fn into_account_id(address: H160) -> AccountId32 {
 	let mut data = [0u8; 24];
 	data[0..4].copy_from_slice(b"evm:");
@@ -137,16 +140,19 @@ pub fn into_account_id(address: H160) -> AccountId32 {
 	AccountId32::from(Into::<[u8; 32]>::into(hash))
 }
 
-pub fn extract_ice_address(
-	payload: &[u8],
-	expected_address: &[u8],
-) -> Result<Vec<u8>, FromHexError> {
-	let expected_string = hex::encode(expected_address);
-	let expected_address = expected_string.as_bytes();
-	const PREFIX_LEN: usize = b"ice_sendTransaction.data.{method.transfer.params.{wallet.".len();
-	let address_len = expected_address.len();
-	let slice = payload[PREFIX_LEN..PREFIX_LEN + address_len].to_vec();
-	hex::decode(slice)
+/// Recover the evm address that signed given message with
+/// ethereum's `personal_sign` i.e message prefixed with
+/// `\x19Ethereum Signed Message:\n` and it's length
+pub fn recover_evm_address(signature: &[u8; 65], message: &[u8]) -> Option<H160> {
+	let mut prefixed_message =
+		codec::alloc::format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+	prefixed_message.extend_from_slice(message);
+
+	let message_hash = sp_io::hashing::keccak_256(&prefixed_message);
+	let public_key = sp_io::crypto::secp256k1_ecdsa_recover(signature, &message_hash).ok()?;
+	let address = sp_io::hashing::keccak_256(&public_key);
+
+	Some(H160::from_slice(&address[12..]))
 }
 
 pub fn to_hex_string<T: Clone + Into<Vec<u8>>>(bytes: &T) -> String {
@@ -9,6 +9,22 @@ pub trait WeightInfo {
 	fn dispatch_exchange_claim() -> Weight;
 	fn update_airdrop_state() -> Weight;
 	fn change_merkle_root() -> Weight;
+	fn dispatch_user_claims_batch(n: u32) -> Weight;
+	fn create_campaign() -> Weight;
+	fn update_campaign_state() -> Weight;
+	fn set_vesting_terms() -> Weight;
+	fn set_claim_deadline() -> Weight;
+	fn sweep_unclaimed_funds() -> Weight;
+	fn add_exchange_account() -> Weight;
+	fn update_exchange_account() -> Weight;
+	fn remove_exchange_account() -> Weight;
+	fn dispatch_evm_user_claim() -> Weight;
+	fn complete_partial_claim() -> Weight;
+	fn set_creditor_funding() -> Weight;
+	fn validate_ice_signature_sr25519() -> Weight;
+	fn validate_ice_signature_ed25519() -> Weight;
+	fn validate_ice_signature_ecdsa() -> Weight;
+	fn revoke_icon_address() -> Weight;
 }
 
 /// Weight functions for `pallet_airdrop`.
@@ -25,42 +41,54 @@ impl<T: frame_system::Config> WeightInfo for AirDropWeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
 	// Storage: Airdrop AirdropChainState (r:1 w:0)
-	// Storage: Airdrop MerkleRoot (r:1 w:0)
-	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
 	// Storage: Airdrop IceIconMap (r:1 w:1)
-	// Storage: Airdrop CreditorAccount (r:1 w:0)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
 	// Storage: System Number (r:1 w:0)
 	// Storage: System ExecutionPhase (r:1 w:0)
 	// Storage: System EventCount (r:1 w:1)
 	// Storage: System Events (r:1 w:1)
-	// Storage: Balances Locks (r:1 w:1)
 	fn dispatch_user_claim() -> Weight {
 		Weight::from_ref_time(246_184_000)
-			.saturating_add(T::DbWeight::get().reads(13))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	// Storage: Airdrop AirdropChainState (r:1 w:0)
-	// Storage: Airdrop ExchangeAccountsMap (r:1 w:0)
-	// Storage: Airdrop MerkleRoot (r:1 w:0)
-	// Storage: Airdrop CreditorAccount (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop SettledExchangeAccounts (r:1 w:1)
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
-	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
 	// Storage: System Number (r:1 w:0)
 	// Storage: System ExecutionPhase (r:1 w:0)
 	// Storage: System EventCount (r:1 w:1)
 	// Storage: System Events (r:1 w:1)
-	// Storage: Balances Locks (r:1 w:1)
 	fn dispatch_exchange_claim() -> Weight {
 		Weight::from_ref_time(128_584_000)
 			// Standard Error: 156_000
 			.saturating_add(Weight::from_ref_time(392_000))
-			.saturating_add(T::DbWeight::get().reads(14))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
 	// Storage: Airdrop AirdropChainState (r:1 w:1)
 	// Storage: System Number (r:1 w:0)
@@ -72,7 +100,7 @@ impl<T: frame_system::Config> WeightInfo for AirDropWeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	// Storage: Airdrop MerkleRoot (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:1 w:1)
 	// Storage: System Number (r:1 w:0)
 	// Storage: System ExecutionPhase (r:1 w:0)
 	// Storage: System EventCount (r:1 w:1)
@@ -82,4 +110,201 @@ impl<T: frame_system::Config> WeightInfo for AirDropWeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
+	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	/// The range of component `n` is `[1, 10]`.
+	fn dispatch_user_claims_batch(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_871_000)
+			.saturating_add(Weight::from_ref_time(229_463_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Airdrop NextCampaignId (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:0 w:1)
+	// Storage: Airdrop CreditorCampaign (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn create_campaign() -> Weight {
+		Weight::from_ref_time(28_917_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn update_campaign_state() -> Weight {
+		Weight::from_ref_time(25_806_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn set_vesting_terms() -> Weight {
+		Weight::from_ref_time(26_112_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn set_claim_deadline() -> Weight {
+		Weight::from_ref_time(26_305_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Locks (r:1 w:0)
+	// Storage: Airdrop CreditorCampaign (r:0 w:1)
+	// Storage: Airdrop FundedCampaigns (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:0 w:1)
+	// Storage: Airdrop CreditorBalanceIsLow (r:0 w:1)
+	// Storage: Airdrop PayloadsToClear (r:0 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:0)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn sweep_unclaimed_funds() -> Weight {
+		Weight::from_ref_time(52_418_000)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Airdrop SettledExchangeAccounts (r:1 w:0)
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn add_exchange_account() -> Weight {
+		Weight::from_ref_time(24_871_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn update_exchange_account() -> Weight {
+		Weight::from_ref_time(22_950_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn remove_exchange_account() -> Weight {
+		Weight::from_ref_time(22_614_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
+	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn dispatch_evm_user_claim() -> Weight {
+		Weight::from_ref_time(251_307_000)
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn complete_partial_claim() -> Weight {
+		Weight::from_ref_time(196_482_000)
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop FundedCampaigns (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:0 w:1)
+	// Storage: Airdrop CreditorBalanceIsLow (r:0 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn set_creditor_funding() -> Weight {
+		Weight::from_ref_time(24_851_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn validate_ice_signature_sr25519() -> Weight {
+		Weight::from_ref_time(47_210_000)
+	}
+	fn validate_ice_signature_ed25519() -> Weight {
+		Weight::from_ref_time(45_638_000)
+	}
+	fn validate_ice_signature_ecdsa() -> Weight {
+		Weight::from_ref_time(61_904_000)
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System ExecutionPhase (r:1 w:0)
+	// Storage: System EventCount (r:1 w:1)
+	// Storage: System Events (r:1 w:1)
+	fn revoke_icon_address() -> Weight {
+		Weight::from_ref_time(21_732_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
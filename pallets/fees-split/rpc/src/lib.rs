@@ -0,0 +1,125 @@
+//! RPC interface for the fees-split pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_fees_split_runtime_api::{
+	EraFeeTotals, EraIndex, FeeTotals, FeesSplitApi as FeesSplitRuntimeApi,
+};
+
+#[rpc(client, server)]
+pub trait FeesSplitApi<BlockHash, ResponseType> {
+	/// Era the given block belongs to
+	#[method(name = "feesSplit_currentEra")]
+	fn current_era(&self, at: Option<BlockHash>) -> RpcResult<EraIndex>;
+
+	/// Fee totals of given era or of current era when not given.
+	/// Returns null for eras that are yet to come or already pruned
+	#[method(name = "feesSplit_eraTotals")]
+	fn era_totals(
+		&self,
+		era: Option<EraIndex>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ResponseType>>;
+
+	/// Fee totals of every era that is still kept, oldest first
+	#[method(name = "feesSplit_recentTotals")]
+	fn recent_totals(&self, at: Option<BlockHash>) -> RpcResult<Vec<ResponseType>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query fee accounting.
+pub struct FeesSplit<C, P> {
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> FeesSplit<C, P> {
+	/// Creates a new instance of the FeesSplit Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, Balance, BlockNumber>
+	FeesSplitApiServer<<Block as BlockT>::Hash, EraFeeTotals<Balance, BlockNumber>>
+	for FeesSplit<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: FeesSplitRuntimeApi<Block, Balance, BlockNumber>,
+	Balance: Codec + std::fmt::Display + Send + Sync + 'static,
+	BlockNumber: Codec + serde::Serialize + Send + Sync + 'static,
+{
+	fn current_era(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<EraIndex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.current_era(&at)
+			.map_err(|e| runtime_error("Unable to query current era.", e))
+	}
+
+	fn era_totals(
+		&self,
+		era: Option<EraIndex>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<EraFeeTotals<Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let era = match era {
+			Some(era) => era,
+			None => api
+				.current_era(&at)
+				.map_err(|e| runtime_error("Unable to query current era.", e))?,
+		};
+
+		api.era_totals(&at, era)
+			.map_err(|e| runtime_error("Unable to query era fee totals.", e))
+	}
+
+	fn recent_totals(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<EraFeeTotals<Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.recent_totals(&at)
+			.map_err(|e| runtime_error("Unable to query recent fee totals.", e))
+	}
+}
+
+fn runtime_error(message: &str, e: sp_api::ApiError) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(e.to_string()),
+	))
+	.into()
+}
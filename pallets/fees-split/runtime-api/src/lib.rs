@@ -0,0 +1,24 @@
+//! Runtime API definition for the fees-split pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_fees_split::{EraFeeTotals, EraIndex, FeeTotals};
+
+sp_api::decl_runtime_apis! {
+	pub trait FeesSplitApi<Balance, BlockNumber> where
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Era the current block belongs to
+		fn current_era() -> EraIndex;
+
+		/// Fee totals of given era. None if era is yet to come or already pruned
+		fn era_totals(era: EraIndex) -> Option<EraFeeTotals<Balance, BlockNumber>>;
+
+		/// Fee totals of every era that is still kept, oldest first
+		fn recent_totals() -> Vec<EraFeeTotals<Balance, BlockNumber>>;
+	}
+}
@@ -3,24 +3,246 @@
 
 pub use pallet::*;
 
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::{
+	traits::{AtLeast32BitUnsigned, CheckedDiv, Saturating, Zero},
+	PerThing, Percent, SaturatedConversion,
+};
+use frame_support::traits::Imbalance;
 use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
 
 const MAX_PERCENT: u32 = 100;
 
+/// Most eras whose totals are pruned in a single block
+const MAX_PRUNED_ERAS: EraIndex = 8;
+
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Where a share of collected fee ends up
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum FeeDestination<AccountId> {
+	/// Treasury pot
+	Treasury,
+	/// Author of the block fee was collected in
+	Author,
+	/// Removed from total issuance
+	Burn,
+	/// Any other account e.g staking rewards pot
+	Account(AccountId),
+	/// Account the charged call designate e.g deployer of called contract.
+	/// Only allowed in routing rules. Share is split as per default
+	/// fee table when call has no beneficiary
+	Beneficiary,
+}
+
+/// Calls a routing rule applies to. Rule without call index
+/// applies to every call of the pallet
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallFilter {
+	pub pallet_index: u8,
+	pub call_index: Option<u8>,
+}
+
+impl CallFilter {
+	pub fn pallet(pallet_index: u8) -> Self {
+		Self {
+			pallet_index,
+			call_index: None,
+		}
+	}
+
+	pub fn call(pallet_index: u8, call_index: u8) -> Self {
+		Self {
+			pallet_index,
+			call_index: Some(call_index),
+		}
+	}
+}
+
+/// Single entry of distribution table
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DistributionShare<AccountId> {
+	pub destination: FeeDestination<AccountId>,
+	pub share: Percent,
+}
+
+impl<AccountId> DistributionShare<AccountId> {
+	pub fn new(destination: FeeDestination<AccountId>, share: Percent) -> Self {
+		Self { destination, share }
+	}
+}
+
+pub type DistributionShareOf<T> = DistributionShare<<T as frame_system::Config>::AccountId>;
+
+/// Distribution table whose shares sum up to 100%
+pub type DistributionTableOf<T> =
+	BoundedVec<DistributionShareOf<T>, <T as Config>::MaxBeneficiaries>;
+
+/// Index of the block window fees are accounted in
+pub type EraIndex = u32;
+
+/// Balances are serialized as strings since JSON numbers
+/// can not hold every `u128` without loss of precision
+#[cfg(feature = "std")]
+mod serde_balance {
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer, T: std::fmt::Display>(
+		t: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&t.to_string())
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		s.parse::<T>()
+			.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+	}
+}
+
+/// Running totals of fee collected within an era and where it went
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(
+		rename_all = "camelCase",
+		bound(
+			serialize = "Balance: std::fmt::Display",
+			deserialize = "Balance: std::str::FromStr"
+		)
+	)
+)]
+pub struct FeeTotals<Balance> {
+	/// Transaction fee collected excluding tips
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub fees: Balance,
+
+	/// Tips collected
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub tips: Balance,
+
+	/// Part of fees and tips given to treasury
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub treasury: Balance,
+
+	/// Part of fees and tips given to block authors
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub author: Balance,
+
+	/// Part of fees and tips removed from total issuance
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub burned: Balance,
+
+	/// Part of fees and tips given to any other account
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub others: Balance,
+}
+
+/// Fee totals of an era along with the blocks it covers
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(
+		rename_all = "camelCase",
+		bound(
+			serialize = "Balance: std::fmt::Display, BlockNumber: serde::Serialize",
+			deserialize = "Balance: std::str::FromStr, BlockNumber: serde::Deserialize<'de>"
+		)
+	)
+)]
+pub struct EraFeeTotals<Balance, BlockNumber> {
+	pub era: EraIndex,
+
+	/// First block of the era
+	pub start_block: BlockNumber,
+
+	pub totals: FeeTotals<Balance>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Share of fee that goes to treasury have been updated
+		TreasuryCutChanged { old: Percent, new: Percent },
+
+		/// Distribution of transaction fee have been updated
+		FeeDistributionSet { table: DistributionTableOf<T> },
+
+		/// Distribution of transaction tip have been updated
+		TipDistributionSet { table: DistributionTableOf<T> },
+
+		/// Fee of calls matching filter is distributed as per table
+		RoutingRuleSet {
+			filter: CallFilter,
+			table: DistributionTableOf<T>,
+		},
+
+		/// Fee of calls matching filter follow default distribution again
+		RoutingRuleRemoved { filter: CallFilter },
+	}
+
 	#[pallet::error]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// Percent value is greater than 100
+		PercentOutOfRange,
+
+		/// Shares of distribution table do not sum up to 100%
+		InvalidDistribution,
+
+		/// Same destination appears more than once in distribution table
+		DuplicateDestination,
+
+		/// Beneficiary destination is only valid in routing rules
+		BeneficiaryNotAllowed,
+
+		/// No routing rule is set for given filter
+		NoSuchRoutingRule,
+	}
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {}
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Maximum number of entries in a distribution table
+		#[pallet::constant]
+		type MaxBeneficiaries: Get<u32>;
+
+		/// Balance type fees are accounted in
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+
+		/// Number of blocks fee totals are accumulated over
+		#[pallet::constant]
+		type EraLength: Get<Self::BlockNumber>;
+
+		/// Number of eras, including the current one, whose totals are kept
+		#[pallet::constant]
+		type RetainedEras: Get<u32>;
+	}
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -51,36 +273,369 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			<TreasuryCutPercent<T>>::put(self.treasury_cut_percent);
+			let cut = Pallet::<T>::treasury_cut_from(self.treasury_cut_percent)
+				.expect("Treasury cut cannot exceed 100 percent");
+			<TreasuryCutPercent<T>>::put(cut);
 		}
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn treasury_cut_percent)]
-	pub type TreasuryCutPercent<T> = StorageValue<_, u32, ValueQuery>;
+	pub type TreasuryCutPercent<T> = StorageValue<_, Percent, ValueQuery>;
+
+	/// How transaction fee is distributed. When empty fee is split
+	/// between treasury and author as per `TreasuryCutPercent`
+	#[pallet::storage]
+	#[pallet::getter(fn fee_distribution)]
+	pub type FeeDistribution<T: Config> = StorageValue<_, DistributionTableOf<T>, ValueQuery>;
+
+	/// How transaction tip is distributed. When empty whole tip goes to author
+	#[pallet::storage]
+	#[pallet::getter(fn tip_distribution)]
+	pub type TipDistribution<T: Config> = StorageValue<_, DistributionTableOf<T>, ValueQuery>;
+
+	/// Distribution of fee paid for calls matching the filter.
+	/// Takes precedence over `FeeDistribution`
+	#[pallet::storage]
+	#[pallet::getter(fn routing_rule)]
+	pub type RoutingRules<T: Config> =
+		StorageMap<_, Twox64Concat, CallFilter, DistributionTableOf<T>, OptionQuery>;
+
+	/// Fee collected in an era and where it went. Only
+	/// last `RetainedEras` eras are kept
+	#[pallet::storage]
+	pub type EraTotals<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, FeeTotals<T::Balance>, ValueQuery>;
+
+	/// Oldest era whose totals may still be stored. Every era
+	/// before it has already been pruned
+	#[pallet::storage]
+	pub type PruneCursor<T> = StorageValue<_, EraIndex, ValueQuery>;
+
+	/// Account that deployed given contract. Rewarded with `Beneficiary`
+	/// share of fee paid for calling the contract
+	#[pallet::storage]
+	#[pallet::getter(fn contract_deployer)]
+	pub type ContractDeployers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// Prune every era that fell out of the window, even the ones
+			// skipped over, a few eras per block
+			let oldest_kept = Self::era_of(now).saturating_sub(Self::retained_eras() - 1);
+			let cursor = <PruneCursor<T>>::get();
+			if cursor >= oldest_kept {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let pruned_until = oldest_kept.min(cursor.saturating_add(MAX_PRUNED_ERAS));
+			for era in cursor..pruned_until {
+				<EraTotals<T>>::remove(era);
+			}
+			<PruneCursor<T>>::put(pruned_until);
+
+			T::DbWeight::get().reads_writes(1, (pruned_until - cursor) as u64 + 1)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Set share of fee, in percent, that goes to treasury
+		/// when no fee distribution is set
 		#[pallet::call_index(0)]
 		#[pallet::weight((
-		WeightInfo::<T>::set_config_with_u32(),
+		WeightInfo::<T>::set_treasury_cut(),
+		DispatchClass::Operational,
+		))]
+		pub fn set_treasury_cut_percent(origin: OriginFor<T>, new: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			let new = Self::treasury_cut_from(new)?;
+
+			let old = <TreasuryCutPercent<T>>::mutate(|cut| sp_std::mem::replace(cut, new));
+			Self::deposit_event(Event::<T>::TreasuryCutChanged { old, new });
+
+			Ok(())
+		}
+
+		/// Set distribution table of transaction fee.
+		/// Empty table fallback to treasury cut
+		#[pallet::call_index(1)]
+		#[pallet::weight((
+		WeightInfo::<T>::set_distribution(),
 		DispatchClass::Operational,
 		))]
-		pub fn set_treasury_cut_percent(origin: OriginFor<T>, val: u32) -> DispatchResult {
+		pub fn set_fee_distribution(
+			origin: OriginFor<T>,
+			table: DistributionTableOf<T>,
+		) -> DispatchResult {
 			ensure_root(origin)?;
+			Self::ensure_valid_distribution(&table)?;
+			Self::ensure_no_beneficiary(&table)?;
 
-			if val <= MAX_PERCENT {
-				<TreasuryCutPercent<T>>::set(val);
+			<FeeDistribution<T>>::put(&table);
+			Self::deposit_event(Event::<T>::FeeDistributionSet { table });
+
+			Ok(())
+		}
+
+		/// Set distribution table of transaction tip.
+		/// Empty table fallback to giving whole tip to author
+		#[pallet::call_index(2)]
+		#[pallet::weight((
+		WeightInfo::<T>::set_distribution(),
+		DispatchClass::Operational,
+		))]
+		pub fn set_tip_distribution(
+			origin: OriginFor<T>,
+			table: DistributionTableOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_valid_distribution(&table)?;
+			Self::ensure_no_beneficiary(&table)?;
+
+			<TipDistribution<T>>::put(&table);
+			Self::deposit_event(Event::<T>::TipDistributionSet { table });
+
+			Ok(())
+		}
+
+		/// Distribute fee of calls matching `filter` as per `table`
+		#[pallet::call_index(3)]
+		#[pallet::weight((
+		WeightInfo::<T>::set_distribution(),
+		DispatchClass::Operational,
+		))]
+		pub fn set_routing_rule(
+			origin: OriginFor<T>,
+			filter: CallFilter,
+			table: DistributionTableOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!table.is_empty(), Error::<T>::InvalidDistribution);
+			Self::ensure_valid_distribution(&table)?;
+
+			<RoutingRules<T>>::insert(filter, &table);
+			Self::deposit_event(Event::<T>::RoutingRuleSet { filter, table });
+
+			Ok(())
+		}
+
+		/// Let fee of calls matching `filter` follow default distribution
+		#[pallet::call_index(4)]
+		#[pallet::weight((
+		WeightInfo::<T>::set_distribution(),
+		DispatchClass::Operational,
+		))]
+		pub fn remove_routing_rule(origin: OriginFor<T>, filter: CallFilter) -> DispatchResult {
+			ensure_root(origin)?;
+			<RoutingRules<T>>::take(filter).ok_or(Error::<T>::NoSuchRoutingRule)?;
+
+			Self::deposit_event(Event::<T>::RoutingRuleRemoved { filter });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Treasury cut of given percent which must not exceed 100
+		pub fn treasury_cut_from(percent: u32) -> Result<Percent, Error<T>> {
+			ensure!(percent <= MAX_PERCENT, Error::<T>::PercentOutOfRange);
+			Ok(Percent::from_percent(percent as u8))
+		}
+
+		/// Table must either be empty or have distinct destinations
+		/// whose shares sum up to exactly 100%
+		pub fn ensure_valid_distribution(table: &[DistributionShareOf<T>]) -> DispatchResult {
+			if table.is_empty() {
+				return Ok(());
 			}
 
+			for (index, entry) in table.iter().enumerate() {
+				ensure!(
+					!table[..index]
+						.iter()
+						.any(|other| other.destination == entry.destination),
+					Error::<T>::DuplicateDestination
+				);
+			}
+
+			let total: u32 = table
+				.iter()
+				.map(|entry| entry.share.deconstruct() as u32)
+				.sum();
+			ensure!(total == MAX_PERCENT, Error::<T>::InvalidDistribution);
+
 			Ok(())
 		}
+
+		fn ensure_no_beneficiary(table: &[DistributionShareOf<T>]) -> DispatchResult {
+			ensure!(
+				!table
+					.iter()
+					.any(|entry| entry.destination == FeeDestination::Beneficiary),
+				Error::<T>::BeneficiaryNotAllowed
+			);
+
+			Ok(())
+		}
+
+		/// Routing rule of given call if any. Rule of the exact call
+		/// is preferred over the rule of whole pallet
+		pub fn call_table(pallet_index: u8, call_index: u8) -> Option<Vec<DistributionShareOf<T>>> {
+			Self::routing_rule(CallFilter::call(pallet_index, call_index))
+				.or_else(|| Self::routing_rule(CallFilter::pallet(pallet_index)))
+				.map(BoundedVec::into_inner)
+		}
+
+		/// Distribution table currently in effect for transaction fee
+		pub fn fee_table() -> Vec<DistributionShareOf<T>> {
+			let table = Self::fee_distribution();
+			if !table.is_empty() {
+				return table.into_inner();
+			}
+
+			let treasury_cut = Self::treasury_cut_percent();
+			sp_std::vec![
+				DistributionShare::new(FeeDestination::Treasury, treasury_cut),
+				DistributionShare::new(
+					FeeDestination::Author,
+					Percent::from_parts(100 - treasury_cut.deconstruct())
+				),
+			]
+		}
+
+		/// Distribution table currently in effect for transaction tip
+		pub fn tip_table() -> Vec<DistributionShareOf<T>> {
+			let table = Self::tip_distribution();
+			if !table.is_empty() {
+				return table.into_inner();
+			}
+
+			sp_std::vec![DistributionShare::new(
+				FeeDestination::Author,
+				Percent::one()
+			)]
+		}
+
+		/// Era given block belongs to. Every block is in era 0
+		/// when era length is zero
+		pub fn era_of(block: T::BlockNumber) -> EraIndex {
+			block
+				.checked_div(&T::EraLength::get())
+				.unwrap_or_else(Zero::zero)
+				.saturated_into()
+		}
+
+		/// Era the current block belongs to
+		pub fn current_era() -> EraIndex {
+			Self::era_of(<frame_system::Pallet<T>>::block_number())
+		}
+
+		fn retained_eras() -> EraIndex {
+			T::RetainedEras::get().max(1)
+		}
+
+		/// Fee totals of given era along with it's first block.
+		/// None if era is yet to come or already pruned
+		pub fn era_totals(era: EraIndex) -> Option<EraFeeTotals<T::Balance, T::BlockNumber>> {
+			let current = Self::current_era();
+			if era > current || era.saturating_add(Self::retained_eras()) <= current {
+				return None;
+			}
+
+			Some(EraFeeTotals {
+				era,
+				start_block: T::EraLength::get().saturating_mul(era.into()),
+				totals: <EraTotals<T>>::get(era),
+			})
+		}
+
+		/// Fee totals of every era that is still kept, oldest first
+		pub fn recent_totals() -> Vec<EraFeeTotals<T::Balance, T::BlockNumber>> {
+			let current = Self::current_era();
+			let oldest = current.saturating_sub(Self::retained_eras() - 1);
+			(oldest..=current).filter_map(Self::era_totals).collect()
+		}
+
+		/// Record the account that deployed `contract`
+		pub fn note_contract_deployer(contract: &T::AccountId, deployer: &T::AccountId) {
+			<ContractDeployers<T>>::insert(contract, deployer);
+		}
+
+		/// Forget the account that deployed `contract`
+		pub fn forget_contract_deployer(contract: &T::AccountId) {
+			<ContractDeployers<T>>::remove(contract);
+		}
+
+		fn note(record: impl FnOnce(&mut FeeTotals<T::Balance>)) {
+			<EraTotals<T>>::mutate(Self::current_era(), record);
+		}
+
+		/// Account transaction fee collected excluding tip
+		pub fn note_fee(amount: T::Balance) {
+			Self::note(|totals| totals.fees.saturating_accrue(amount));
+		}
+
+		/// Account tip collected
+		pub fn note_tip(amount: T::Balance) {
+			Self::note(|totals| totals.tips.saturating_accrue(amount));
+		}
+
+		/// Account part of fee or tip that reached it's destination
+		pub fn note_share(destination: &FeeDestination<T::AccountId>, amount: T::Balance) {
+			Self::note(|totals| {
+				let total = match destination {
+					FeeDestination::Treasury => &mut totals.treasury,
+					FeeDestination::Author => &mut totals.author,
+					FeeDestination::Burn => &mut totals.burned,
+					FeeDestination::Account(_) | FeeDestination::Beneficiary => &mut totals.others,
+				};
+				total.saturating_accrue(amount);
+			});
+		}
+	}
+}
+
+/// Split `amount` as per `table` and hand over every part to `handle`.
+/// Rounding remainder goes to the last entry so nothing is left over
+pub fn distribute<AccountId, Balance, I>(
+	amount: I,
+	table: &[DistributionShare<AccountId>],
+	mut handle: impl FnMut(&FeeDestination<AccountId>, I),
+) where
+	Balance: AtLeast32BitUnsigned + Copy,
+	I: Imbalance<Balance>,
+{
+	let total = amount.peek();
+	let mut remaining = amount;
+
+	if let Some((last, rest)) = table.split_last() {
+		for entry in rest {
+			let (part, left) = remaining.split(entry.share.mul_floor(total));
+			remaining = left;
+			handle(&entry.destination, part);
+		}
+		handle(&last.destination, remaining);
 	}
 }
 
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: Config> WeightInfo<T> {
-	pub fn set_config_with_u32() -> Weight {
-		Weight::from_ref_time(10_000_000).saturating_add(T::DbWeight::get().writes(1))
+	pub fn set_treasury_cut() -> Weight {
+		Weight::from_ref_time(10_000_000).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
+	pub fn set_distribution() -> Weight {
+		Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Splitting a fee along with it's tip and accounting both in
+	/// totals of current era. Totals are written once per share but
+	/// every write after the first one hits the same cached key
+	pub fn split_fee() -> Weight {
+		// Fee and tip distribution, treasury cut and totals of current era
+		T::DbWeight::get().reads_writes(4, 1)
 	}
 }
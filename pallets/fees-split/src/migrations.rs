@@ -0,0 +1,88 @@
+//! Storage migrations of fees-split pallet.
+//! Each migration lives in it's own module named after the storage version
+//! it migrates to and is expected to be added in runtime's `Executive`
+
+use crate::{Config, Pallet};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	sp_runtime::Percent,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Store treasury cut as `Percent` instead of raw `u32`
+pub mod v1 {
+	use super::*;
+
+	/// Migrate `TreasuryCutPercent` from `u32` into `Percent`.
+	/// Value above 100 was never accepted by the call and is clamped
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				log::info!(
+					target: "runtime::fees-split",
+					"Migration to v1 skipped. On chain version: {on_chain_version:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let translated = crate::TreasuryCutPercent::<T>::translate::<u32, _>(|old| {
+				old.map(|old| Percent::from_percent(old.min(100) as u8))
+			});
+			if translated.is_err() {
+				log::error!(
+					target: "runtime::fees-split",
+					"Treasury cut could not be decoded as u32. Resetting it to zero"
+				);
+				crate::TreasuryCutPercent::<T>::kill();
+			}
+
+			Pallet::<T>::current_storage_version().put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::fees-split",
+				"Migrated to v1. Treasury cut: {:?}",
+				crate::TreasuryCutPercent::<T>::get()
+			);
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() < 1,
+				"Fees split storage is already at v1"
+			);
+
+			let old = frame_support::storage::unhashed::get::<u32>(
+				&crate::TreasuryCutPercent::<T>::hashed_key(),
+			)
+			.unwrap_or_default();
+			Ok(old.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let old = u32::decode(&mut &state[..])
+				.map_err(|_| "Fees split pre_upgrade state can't be decoded")?;
+
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Fees split storage version not updated to v1"
+			);
+			ensure!(
+				crate::TreasuryCutPercent::<T>::get() == Percent::from_percent(old.min(100) as u8),
+				"Treasury cut have not been migrated"
+			);
+
+			Ok(())
+		}
+	}
+}
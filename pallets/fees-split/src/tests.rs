@@ -0,0 +1,365 @@
+use crate::{
+	distribute, mock::*, CallFilter, DistributionShare, DistributionShareOf, DistributionTableOf,
+	EraFeeTotals, Error, Event, FeeDestination, FeeTotals,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, GetStorageVersion, Hooks, Imbalance, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::{DispatchError, Percent};
+
+const POT: AccountId = 7;
+
+fn share(destination: FeeDestination<AccountId>, percent: u8) -> DistributionShareOf<Test> {
+	DistributionShare::new(destination, Percent::from_percent(percent))
+}
+
+fn table(shares: Vec<DistributionShareOf<Test>>) -> DistributionTableOf<Test> {
+	shares.try_into().unwrap()
+}
+
+#[test]
+fn treasury_cut_is_set_by_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeesSplit::set_treasury_cut_percent(RuntimeOrigin::signed(1), 40),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			FeesSplit::set_treasury_cut_percent(RuntimeOrigin::root(), 101),
+			Error::<Test>::PercentOutOfRange
+		);
+
+		assert_ok!(FeesSplit::set_treasury_cut_percent(
+			RuntimeOrigin::root(),
+			40
+		));
+		assert_eq!(FeesSplit::treasury_cut_percent(), Percent::from_percent(40));
+		System::assert_last_event(
+			Event::TreasuryCutChanged {
+				old: Percent::from_percent(80),
+				new: Percent::from_percent(40),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn migrate_treasury_cut_to_percent() {
+	new_test_ext().execute_with(|| {
+		let key = crate::TreasuryCutPercent::<Test>::hashed_key();
+		let migrate = |old: u32| {
+			// Value stored as u32 before storage version 1
+			frame_support::storage::unhashed::put(&key, &old);
+			StorageVersion::new(0).put::<FeesSplit>();
+
+			crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(FeesSplit::on_chain_storage_version(), 1);
+			FeesSplit::treasury_cut_percent()
+		};
+
+		assert_eq!(migrate(30), Percent::from_percent(30));
+		assert_eq!(migrate(150), Percent::from_percent(100));
+
+		// Already migrated value is left as is
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			FeesSplit::treasury_cut_percent(),
+			Percent::from_percent(100)
+		);
+	});
+}
+
+#[test]
+fn default_tables_follow_treasury_cut() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			FeesSplit::fee_table(),
+			vec![
+				share(FeeDestination::Treasury, 80),
+				share(FeeDestination::Author, 20)
+			]
+		);
+		assert_eq!(
+			FeesSplit::tip_table(),
+			vec![share(FeeDestination::Author, 100)]
+		);
+
+		let fee_table = table(vec![
+			share(FeeDestination::Treasury, 50),
+			share(FeeDestination::Burn, 50),
+		]);
+		assert_ok!(FeesSplit::set_fee_distribution(
+			RuntimeOrigin::root(),
+			fee_table.clone()
+		));
+		System::assert_last_event(
+			Event::FeeDistributionSet {
+				table: fee_table.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(FeesSplit::fee_table(), fee_table.into_inner());
+
+		// Empty table fallback to treasury cut again
+		assert_ok!(FeesSplit::set_fee_distribution(
+			RuntimeOrigin::root(),
+			table(vec![])
+		));
+		assert_eq!(FeesSplit::fee_table().len(), 2);
+	});
+}
+
+#[test]
+fn distribution_table_is_validated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeesSplit::set_fee_distribution(
+				RuntimeOrigin::root(),
+				table(vec![
+					share(FeeDestination::Treasury, 60),
+					share(FeeDestination::Burn, 30),
+				])
+			),
+			Error::<Test>::InvalidDistribution
+		);
+		assert_noop!(
+			FeesSplit::set_tip_distribution(
+				RuntimeOrigin::root(),
+				table(vec![
+					share(FeeDestination::Account(POT), 50),
+					share(FeeDestination::Account(POT), 50),
+				])
+			),
+			Error::<Test>::DuplicateDestination
+		);
+		assert_noop!(
+			FeesSplit::set_fee_distribution(
+				RuntimeOrigin::root(),
+				table(vec![share(FeeDestination::Beneficiary, 100)])
+			),
+			Error::<Test>::BeneficiaryNotAllowed
+		);
+		assert_noop!(
+			FeesSplit::set_tip_distribution(
+				RuntimeOrigin::signed(1),
+				table(vec![share(FeeDestination::Burn, 100)])
+			),
+			DispatchError::BadOrigin
+		);
+
+		let tip_table = table(vec![
+			share(FeeDestination::Author, 40),
+			share(FeeDestination::Account(POT), 60),
+		]);
+		assert_ok!(FeesSplit::set_tip_distribution(
+			RuntimeOrigin::root(),
+			tip_table.clone()
+		));
+		System::assert_last_event(
+			Event::TipDistributionSet {
+				table: tip_table.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(FeesSplit::tip_table(), tip_table.into_inner());
+	});
+}
+
+#[test]
+fn rule_of_exact_call_takes_precedence() {
+	new_test_ext().execute_with(|| {
+		let pallet_rule = table(vec![share(FeeDestination::Burn, 100)]);
+		let call_rule = table(vec![
+			share(FeeDestination::Beneficiary, 30),
+			share(FeeDestination::Treasury, 70),
+		]);
+
+		assert_eq!(FeesSplit::call_table(5, 0), None);
+		assert_noop!(
+			FeesSplit::set_routing_rule(
+				RuntimeOrigin::root(),
+				CallFilter::pallet(5),
+				table(vec![])
+			),
+			Error::<Test>::InvalidDistribution
+		);
+
+		assert_ok!(FeesSplit::set_routing_rule(
+			RuntimeOrigin::root(),
+			CallFilter::pallet(5),
+			pallet_rule.clone()
+		));
+		assert_ok!(FeesSplit::set_routing_rule(
+			RuntimeOrigin::root(),
+			CallFilter::call(5, 1),
+			call_rule.clone()
+		));
+		System::assert_last_event(
+			Event::RoutingRuleSet {
+				filter: CallFilter::call(5, 1),
+				table: call_rule.clone(),
+			}
+			.into(),
+		);
+
+		assert_eq!(
+			FeesSplit::call_table(5, 0),
+			Some(pallet_rule.clone().into_inner())
+		);
+		assert_eq!(FeesSplit::call_table(5, 1), Some(call_rule.into_inner()));
+		assert_eq!(FeesSplit::call_table(6, 1), None);
+
+		assert_ok!(FeesSplit::remove_routing_rule(
+			RuntimeOrigin::root(),
+			CallFilter::call(5, 1)
+		));
+		System::assert_last_event(
+			Event::RoutingRuleRemoved {
+				filter: CallFilter::call(5, 1),
+			}
+			.into(),
+		);
+		assert_eq!(FeesSplit::call_table(5, 1), Some(pallet_rule.into_inner()));
+		assert_noop!(
+			FeesSplit::remove_routing_rule(RuntimeOrigin::root(), CallFilter::call(5, 1)),
+			Error::<Test>::NoSuchRoutingRule
+		);
+	});
+}
+
+#[test]
+fn distribute_leaves_no_remainder() {
+	new_test_ext().execute_with(|| {
+		let shares = vec![
+			share(FeeDestination::Treasury, 33),
+			share(FeeDestination::Burn, 33),
+			share(FeeDestination::Account(POT), 34),
+		];
+
+		let mut parts = vec![];
+		distribute(Balances::issue(101), &shares, |destination, amount| {
+			parts.push((destination.clone(), amount.peek()))
+		});
+
+		assert_eq!(
+			parts,
+			vec![
+				(FeeDestination::Treasury, 33),
+				(FeeDestination::Burn, 33),
+				(FeeDestination::Account(POT), 35),
+			]
+		);
+	});
+}
+
+#[test]
+fn fee_totals_are_accounted_per_era_and_pruned() {
+	new_test_ext().execute_with(|| {
+		FeesSplit::note_fee(100);
+		FeesSplit::note_tip(10);
+		FeesSplit::note_share(&FeeDestination::Treasury, 80);
+		FeesSplit::note_share(&FeeDestination::Author, 30);
+
+		assert_eq!(FeesSplit::current_era(), 0);
+		assert_eq!(
+			FeesSplit::era_totals(0).unwrap().totals,
+			FeeTotals {
+				fees: 100,
+				tips: 10,
+				treasury: 80,
+				author: 30,
+				burned: 0,
+				others: 0,
+			}
+		);
+		assert_eq!(FeesSplit::era_totals(1), None);
+
+		// Totals of next era start over
+		System::set_block_number(ERA_LENGTH);
+		FeesSplit::on_initialize(ERA_LENGTH);
+		FeesSplit::note_fee(1);
+		FeesSplit::note_share(&FeeDestination::Burn, 1);
+		FeesSplit::note_share(&FeeDestination::Account(POT), 2);
+
+		let recent = FeesSplit::recent_totals();
+		assert_eq!(recent.len(), 2);
+		assert_eq!(recent[0].totals.fees, 100);
+		assert_eq!(recent[1].start_block, ERA_LENGTH);
+		assert_eq!(recent[1].totals.burned, 1);
+		assert_eq!(recent[1].totals.others, 2);
+
+		// First era is pruned once it falls out of the window
+		let expiry = ERA_LENGTH * RETAINED_ERAS as u64;
+		System::set_block_number(expiry);
+		FeesSplit::on_initialize(expiry);
+
+		assert_eq!(FeesSplit::era_totals(0), None);
+		assert!(!crate::EraTotals::<Test>::contains_key(0));
+		assert_eq!(FeesSplit::recent_totals().len() as u32, RETAINED_ERAS);
+		assert_eq!(FeesSplit::era_totals(1).unwrap().totals.fees, 1);
+	});
+}
+
+#[test]
+fn skipped_eras_are_pruned_over_few_blocks() {
+	new_test_ext().execute_with(|| {
+		let totals = FeeTotals {
+			fees: 1,
+			..Default::default()
+		};
+		for era in 0..20 {
+			crate::EraTotals::<Test>::insert(era, totals);
+		}
+		let is_kept = |era| crate::EraTotals::<Test>::contains_key(era);
+
+		// Middle of era 20 in which only eras 18 and 19 are still kept
+		let now = ERA_LENGTH * 20 + 1;
+		System::set_block_number(now);
+		FeesSplit::on_initialize(now);
+		assert!(!is_kept(7));
+		assert!(is_kept(8));
+
+		FeesSplit::on_initialize(now + 1);
+		FeesSplit::on_initialize(now + 2);
+		assert!((0..18).all(|era| !is_kept(era)));
+		assert!(is_kept(18) && is_kept(19));
+		assert_eq!(crate::PruneCursor::<Test>::get(), 18);
+	});
+}
+
+#[test]
+fn contract_deployer_is_recorded() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(FeesSplit::contract_deployer(10), None);
+
+		FeesSplit::note_contract_deployer(&10, &POT);
+		assert_eq!(FeesSplit::contract_deployer(10), Some(POT));
+
+		FeesSplit::forget_contract_deployer(&10);
+		assert_eq!(FeesSplit::contract_deployer(10), None);
+	});
+}
+
+#[test]
+fn fee_totals_serialize_balances_as_strings() {
+	let totals = EraFeeTotals {
+		era: 2,
+		start_block: 20_u64,
+		totals: FeeTotals {
+			fees: u128::MAX,
+			tips: 1,
+			..Default::default()
+		},
+	};
+
+	let json = serde_json::to_string(&totals).unwrap();
+	assert!(json.contains(&format!("\"fees\":\"{}\"", u128::MAX)));
+	assert!(json.contains("\"tips\":\"1\""));
+	assert!(json.contains("\"startBlock\":20"));
+	assert_eq!(
+		serde_json::from_str::<EraFeeTotals<u128, u64>>(&json).unwrap(),
+		totals
+	);
+}
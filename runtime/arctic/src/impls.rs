@@ -1,29 +1,11 @@
-use crate::{Authorship, Balances, FeesSplit, NegativeImbalance, Treasury};
-use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
-
-pub struct Author;
-impl OnUnbalanced<NegativeImbalance> for Author {
-	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
-		if let Some(author) = Authorship::author() {
-			Balances::resolve_creating(&author, amount);
-		}
-	}
-}
-pub struct DealWithFees;
-impl OnUnbalanced<NegativeImbalance> for DealWithFees {
-	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
-		let treasury_cut = FeesSplit::treasury_cut_percent();
-		let author_cut = 100 - treasury_cut;
-
-		if let Some(fees) = fees_then_tips.next() {
-			let mut split = fees.ration(treasury_cut, author_cut);
-			if let Some(tips) = fees_then_tips.next() {
-				tips.ration_merge_into(0, 100, &mut split);
-			}
-			Treasury::on_unbalanced(split.0);
-			Author::on_unbalanced(split.1);
-		}
-	}
+use crate::{Runtime, Treasury};
+
+pub type DealWithFees = fee_routing::DealWithFees<Runtime>;
+pub type DealWithEVMFees = fee_routing::DealWithEVMFees<Runtime>;
+pub type ChargeRoutedFees = fee_routing::ChargeRoutedFees<Runtime>;
+
+impl fee_routing::Config for Runtime {
+	type Treasury = Treasury;
 }
 
 #[cfg(test)]
@@ -47,37 +47,193 @@ impl<T: frame_system::Config> pallet_airdrop::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
 	// Storage: Airdrop AirdropChainState (r:1 w:0)
-	// Storage: Airdrop MerkleRoot (r:1 w:0)
-	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
 	// Storage: Airdrop IceIconMap (r:1 w:1)
-	// Storage: Airdrop CreditorAccount (r:1 w:0)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
 	fn dispatch_user_claim() -> Weight {
 		Weight::from_ref_time(829_595_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(9 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: Airdrop AirdropChainState (r:1 w:0)
-	// Storage: Airdrop ExchangeAccountsMap (r:1 w:0)
-	// Storage: Airdrop MerkleRoot (r:1 w:0)
-	// Storage: Airdrop CreditorAccount (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop SettledExchangeAccounts (r:1 w:1)
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
-	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
 	fn dispatch_exchange_claim() -> Weight {
 		Weight::from_ref_time(568_030_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(10 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
-	// Storage: Airdrop MerkleRoot (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:1 w:1)
 	fn change_merkle_root() -> Weight {
 		Weight::from_ref_time(114_144_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
+	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn dispatch_user_claims_batch(n: u32, ) -> Weight {
+		Weight::from_ref_time(112_307_000 as u64)
+			.saturating_add(Weight::from_ref_time(741_826_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((13 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((9 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Airdrop NextCampaignId (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:0 w:1)
+	// Storage: Airdrop CreditorCampaign (r:1 w:1)
+	fn create_campaign() -> Weight {
+		Weight::from_ref_time(118_032_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	fn update_campaign_state() -> Weight {
+		Weight::from_ref_time(112_560_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	fn set_vesting_terms() -> Weight {
+		Weight::from_ref_time(113_705_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	fn set_claim_deadline() -> Weight {
+		Weight::from_ref_time(113_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Locks (r:1 w:0)
+	// Storage: Airdrop CreditorCampaign (r:0 w:1)
+	// Storage: Airdrop FundedCampaigns (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:0 w:1)
+	// Storage: Airdrop CreditorBalanceIsLow (r:0 w:1)
+	// Storage: Airdrop PayloadsToClear (r:0 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:0)
+	fn sweep_unclaimed_funds() -> Weight {
+		Weight::from_ref_time(141_775_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Airdrop SettledExchangeAccounts (r:1 w:0)
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	fn add_exchange_account() -> Weight {
+		Weight::from_ref_time(109_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	fn update_exchange_account() -> Weight {
+		Weight::from_ref_time(107_233_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	fn remove_exchange_account() -> Weight {
+		Weight::from_ref_time(106_918_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
+	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	fn dispatch_evm_user_claim() -> Weight {
+		Weight::from_ref_time(835_112_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	fn complete_partial_claim() -> Weight {
+		Weight::from_ref_time(712_406_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop FundedCampaigns (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:0 w:1)
+	// Storage: Airdrop CreditorBalanceIsLow (r:0 w:1)
+	fn set_creditor_funding() -> Weight {
+		Weight::from_ref_time(98_215_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn validate_ice_signature_sr25519() -> Weight {
+		Weight::from_ref_time(184_372_000 as u64)
+	}
+	fn validate_ice_signature_ed25519() -> Weight {
+		Weight::from_ref_time(178_519_000 as u64)
+	}
+	fn validate_ice_signature_ecdsa() -> Weight {
+		Weight::from_ref_time(241_086_000 as u64)
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:1)
+	fn revoke_icon_address() -> Weight {
+		Weight::from_ref_time(97_604_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
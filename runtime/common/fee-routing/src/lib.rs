@@ -0,0 +1,357 @@
+//! Fee handling shared by runtimes. Fees and tips of substrate and EVM
+//! transactions are split as per distribution tables of `pallet_fees_split`
+//! and fee of calls with a routing rule follow that rule instead
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use codec::Encode;
+use frame_support::{
+	traits::{Currency, Get, Imbalance, IsSubType, OnUnbalanced},
+	weights::Weight,
+};
+use pallet_contracts::AddressGenerator;
+use pallet_evm::{EVMCurrencyAdapter, OnChargeEVMTransaction};
+use pallet_fees_split::{DistributionShare, FeeDestination};
+use pallet_transaction_payment::{CurrencyAdapter, OnChargeTransaction};
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Hash, PostDispatchInfoOf, StaticLookup, UncheckedFrom},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+type AccountIdOf<R> = <R as frame_system::Config>::AccountId;
+type CallOf<R> = <R as frame_system::Config>::RuntimeCall;
+type HashOf<R> = <R as frame_system::Config>::Hash;
+type Balances<R> = pallet_balances::Pallet<R>;
+type FeesSplit<R> = pallet_fees_split::Pallet<R>;
+pub type BalanceOf<R> = <R as pallet_balances::Config>::Balance;
+pub type NegativeImbalanceOf<R> = pallet_balances::NegativeImbalance<R>;
+
+/// Runtime whose fees are split by `pallet_fees_split`
+pub trait Config:
+	pallet_balances::Config
+	+ pallet_authorship::Config
+	+ pallet_fees_split::Config<Balance = BalanceOf<Self>>
+{
+	/// Receiver of `Treasury` share
+	type Treasury: OnUnbalanced<NegativeImbalanceOf<Self>>;
+}
+
+pub struct Author<R>(PhantomData<R>);
+impl<R: Config> OnUnbalanced<NegativeImbalanceOf<R>> for Author<R> {
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<R>) {
+		if let Some(author) = pallet_authorship::Pallet::<R>::author() {
+			Balances::<R>::resolve_creating(&author, amount);
+		}
+	}
+}
+pub struct DealWithFees<R>(PhantomData<R>);
+impl<R: Config> DealWithFees<R> {
+	/// Hand over a part of fee or tip to it's destination
+	fn route(destination: &FeeDestination<AccountIdOf<R>>, amount: NegativeImbalanceOf<R>) {
+		// Share of missing block author is burned
+		let destination = match destination {
+			FeeDestination::Author if pallet_authorship::Pallet::<R>::author().is_none() => {
+				&FeeDestination::Burn
+			}
+			_ => destination,
+		};
+
+		if *destination != FeeDestination::Beneficiary {
+			FeesSplit::<R>::note_share(destination, amount.peek());
+		}
+
+		match destination {
+			FeeDestination::Treasury => R::Treasury::on_unbalanced(amount),
+			FeeDestination::Author => Author::<R>::on_unbalanced(amount),
+			// Dropping the imbalance reduce total issuance
+			FeeDestination::Burn => drop(amount),
+			FeeDestination::Account(account) => {
+				Balances::<R>::resolve_creating(account, amount);
+			}
+			// No call to take beneficiary from
+			FeeDestination::Beneficiary => {
+				pallet_fees_split::distribute(amount, &FeesSplit::<R>::fee_table(), Self::route)
+			}
+		}
+	}
+}
+
+impl<R: Config> OnUnbalanced<NegativeImbalanceOf<R>> for DealWithFees<R> {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalanceOf<R>>) {
+		if let Some(fees) = fees_then_tips.next() {
+			FeesSplit::<R>::note_fee(fees.peek());
+			pallet_fees_split::distribute(fees, &FeesSplit::<R>::fee_table(), Self::route);
+			if let Some(tips) = fees_then_tips.next() {
+				FeesSplit::<R>::note_tip(tips.peek());
+				pallet_fees_split::distribute(tips, &FeesSplit::<R>::tip_table(), Self::route);
+			}
+		}
+	}
+}
+
+/// Charge fee of EVM transactions and split it as `DealWithFees` does.
+/// Base fee follows the fee table and priority fee the tip table
+pub struct DealWithEVMFees<R>(PhantomData<R>);
+type EVMAdapter<R> = EVMCurrencyAdapter<Balances<R>, DealWithEVMFees<R>>;
+
+impl<R: Config> OnUnbalanced<NegativeImbalanceOf<R>> for DealWithEVMFees<R> {
+	fn on_nonzero_unbalanced(base_fee: NegativeImbalanceOf<R>) {
+		FeesSplit::<R>::note_fee(base_fee.peek());
+		pallet_fees_split::distribute(
+			base_fee,
+			&FeesSplit::<R>::fee_table(),
+			DealWithFees::<R>::route,
+		);
+	}
+}
+
+impl<R> OnChargeEVMTransaction<R> for DealWithEVMFees<R>
+where
+	R: Config + pallet_evm::Config,
+	EVMAdapter<R>: OnChargeEVMTransaction<R, LiquidityInfo = Option<NegativeImbalanceOf<R>>>,
+{
+	type LiquidityInfo = Option<NegativeImbalanceOf<R>>;
+
+	fn withdraw_fee(who: &H160, fee: U256) -> Result<Self::LiquidityInfo, pallet_evm::Error<R>> {
+		<EVMAdapter<R> as OnChargeEVMTransaction<R>>::withdraw_fee(who, fee)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &H160,
+		corrected_fee: U256,
+		base_fee: U256,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Self::LiquidityInfo {
+		// Adapter hand over base fee to `Self` and return the priority fee
+		<EVMAdapter<R> as OnChargeEVMTransaction<R>>::correct_and_deposit_fee(
+			who,
+			corrected_fee,
+			base_fee,
+			already_withdrawn,
+		)
+	}
+
+	fn pay_priority_fee(tip: Self::LiquidityInfo) {
+		if let Some(tip) = tip {
+			FeesSplit::<R>::note_tip(tip.peek());
+			pallet_fees_split::distribute(
+				tip,
+				&FeesSplit::<R>::tip_table(),
+				DealWithFees::<R>::route,
+			);
+		}
+	}
+}
+
+/// Routing rule of charged call along with the beneficiary it designate
+pub struct FeeRoute<AccountId> {
+	table: Vec<DistributionShare<AccountId>>,
+	beneficiary: Option<AccountId>,
+}
+
+/// Contract that charged call calls or instantiates. Deployer is
+/// only known when the contract is instantiated by the call
+pub struct TargetContract<AccountId> {
+	contract: AccountId,
+	deployer: Option<AccountId>,
+}
+
+/// Charge transaction fee as `CurrencyAdapter` does but distribute fee of
+/// calls with a routing rule as per that rule instead of `DealWithFees`.
+/// Deployer of contracts is recorded once their instantiation is dispatched
+/// and forgotten once they no longer exist
+pub struct ChargeRoutedFees<R>(PhantomData<R>);
+type SubstrateAdapter<R> = CurrencyAdapter<Balances<R>, DealWithFees<R>>;
+
+impl<R> ChargeRoutedFees<R>
+where
+	R: Config + pallet_contracts::Config,
+	CallOf<R>: IsSubType<pallet_contracts::Call<R>>,
+	AccountIdOf<R>: UncheckedFrom<HashOf<R>> + AsRef<[u8]>,
+{
+	/// Weight of looking up routing rule and beneficiary of charged call,
+	/// of splitting and accounting it's fee and of keeping deployer of
+	/// it's target contract up to date
+	fn weight(target: Option<&TargetContract<AccountIdOf<R>>>) -> Weight {
+		// Rule of the exact call and rule of whole pallet
+		let routing = R::DbWeight::get()
+			.reads(2)
+			.saturating_add(pallet_fees_split::WeightInfo::<R>::split_fee());
+		match target {
+			// Deployer and code hash of the contract along with deployer update
+			Some(_) => routing.saturating_add(R::DbWeight::get().reads_writes(2, 1)),
+			None => routing,
+		}
+	}
+
+	fn route_of(
+		call: &CallOf<R>,
+		target: Option<&TargetContract<AccountIdOf<R>>>,
+	) -> Option<FeeRoute<AccountIdOf<R>>> {
+		// Encoded call always start with pallet index and call index
+		let (pallet_index, call_index) =
+			call.using_encoded(|encoded| Some((*encoded.first()?, *encoded.get(1)?)))?;
+		let table = FeesSplit::<R>::call_table(pallet_index, call_index)?;
+
+		let has_beneficiary = table
+			.iter()
+			.any(|entry| entry.destination == FeeDestination::Beneficiary);
+		let beneficiary = match target {
+			Some(target) if has_beneficiary => Self::beneficiary_of(target),
+			_ => None,
+		};
+
+		Some(FeeRoute { table, beneficiary })
+	}
+
+	/// Account that is rewarded with `Beneficiary` share of call's fee.
+	/// For contract call it is the account which deployed the contract
+	fn beneficiary_of(target: &TargetContract<AccountIdOf<R>>) -> Option<AccountIdOf<R>> {
+		match target.deployer {
+			Some(_) => None,
+			None => FeesSplit::<R>::contract_deployer(&target.contract),
+		}
+	}
+
+	/// Contract that `call` of `who` calls or instantiates. Address of
+	/// instantiated contract is derived as `pallet_contracts` does
+	fn target_of(who: &AccountIdOf<R>, call: &CallOf<R>) -> Option<TargetContract<AccountIdOf<R>>> {
+		let instantiated = |code_hash: &HashOf<R>, data: &[u8], salt: &[u8]| TargetContract {
+			contract: <R as pallet_contracts::Config>::AddressGenerator::generate_address(
+				who, code_hash, data, salt,
+			),
+			deployer: Some(who.clone()),
+		};
+
+		match call.is_sub_type()? {
+			pallet_contracts::Call::call { dest, .. } => Some(TargetContract {
+				contract: R::Lookup::lookup(dest.clone()).ok()?,
+				deployer: None,
+			}),
+			pallet_contracts::Call::instantiate {
+				code_hash,
+				data,
+				salt,
+				..
+			} => Some(instantiated(code_hash, data, salt)),
+			pallet_contracts::Call::instantiate_with_code {
+				code, data, salt, ..
+			} => Some(instantiated(&R::Hashing::hash(code), data, salt)),
+			_ => None,
+		}
+	}
+
+	/// Record deployer of instantiated contract and forget deployer
+	/// of contract that no longer exists once call is dispatched
+	fn settle_deployer(target: TargetContract<AccountIdOf<R>>) {
+		let exists = pallet_contracts::Pallet::<R>::code_hash(&target.contract).is_some();
+		match target.deployer {
+			Some(deployer) if exists => {
+				FeesSplit::<R>::note_contract_deployer(&target.contract, &deployer)
+			}
+			_ if !exists => FeesSplit::<R>::forget_contract_deployer(&target.contract),
+			_ => {}
+		}
+	}
+}
+
+impl<R> OnChargeTransaction<R> for ChargeRoutedFees<R>
+where
+	R: Config + pallet_contracts::Config + pallet_transaction_payment::Config,
+	CallOf<R>: IsSubType<pallet_contracts::Call<R>>,
+	AccountIdOf<R>: UncheckedFrom<HashOf<R>> + AsRef<[u8]>,
+	SubstrateAdapter<R>: OnChargeTransaction<
+		R,
+		Balance = BalanceOf<R>,
+		LiquidityInfo = Option<NegativeImbalanceOf<R>>,
+	>,
+{
+	type Balance = BalanceOf<R>;
+	type LiquidityInfo = (
+		Option<NegativeImbalanceOf<R>>,
+		Option<FeeRoute<AccountIdOf<R>>>,
+		Option<TargetContract<AccountIdOf<R>>>,
+	);
+
+	fn withdraw_fee(
+		who: &AccountIdOf<R>,
+		call: &CallOf<R>,
+		info: &DispatchInfoOf<CallOf<R>>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let paid = <SubstrateAdapter<R> as OnChargeTransaction<R>>::withdraw_fee(
+			who, call, info, fee, tip,
+		)?;
+
+		let target = Self::target_of(who, call);
+		let route = Self::route_of(call, target.as_ref());
+
+		Ok((paid, route, target))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountIdOf<R>,
+		dispatch_info: &DispatchInfoOf<CallOf<R>>,
+		post_info: &PostDispatchInfoOf<CallOf<R>>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let (paid, route, target) = already_withdrawn;
+		// Not part of base weight of extrinsics so weighed once actually done
+		frame_system::Pallet::<R>::register_extra_weight_unchecked(
+			Self::weight(target.as_ref()),
+			dispatch_info.class,
+		);
+		if let Some(target) = target {
+			Self::settle_deployer(target);
+		}
+
+		let (paid, route) = match (paid, route) {
+			(Some(paid), Some(route)) => (paid, route),
+			(paid, _) => {
+				return <SubstrateAdapter<R> as OnChargeTransaction<R>>::correct_and_deposit_fee(
+					who,
+					dispatch_info,
+					post_info,
+					corrected_fee,
+					tip,
+					paid,
+				)
+			}
+		};
+
+		// Refund what was paid over the corrected fee as `CurrencyAdapter` does
+		let refund_amount = paid.peek().saturating_sub(corrected_fee);
+		let refund_imbalance = Balances::<R>::deposit_into_existing(who, refund_amount)
+			.unwrap_or_else(|_| Imbalance::zero());
+		let adjusted_paid = paid
+			.offset(refund_imbalance)
+			.same()
+			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+		let (tip, fee) = adjusted_paid.split(tip);
+		FeesSplit::<R>::note_fee(fee.peek());
+		FeesSplit::<R>::note_tip(tip.peek());
+
+		pallet_fees_split::distribute(fee, &route.table, |destination, amount| {
+			match (destination, &route.beneficiary) {
+				(FeeDestination::Beneficiary, Some(beneficiary)) => {
+					FeesSplit::<R>::note_share(destination, amount.peek());
+					Balances::<R>::resolve_creating(beneficiary, amount);
+				}
+				_ => DealWithFees::<R>::route(destination, amount),
+			}
+		});
+		pallet_fees_split::distribute(tip, &FeesSplit::<R>::tip_table(), DealWithFees::<R>::route);
+
+		Ok(())
+	}
+}
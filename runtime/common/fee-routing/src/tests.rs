@@ -0,0 +1,261 @@
+use crate::{mock::*, ChargeRoutedFees, DealWithEVMFees, DealWithFees, TargetContract};
+
+use codec::Encode;
+use frame_support::{
+	assert_ok,
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced, WithdrawReasons},
+	weights::Weight,
+};
+use pallet_contracts_primitives::Code;
+use pallet_evm::{AddressMapping, OnChargeEVMTransaction};
+use pallet_fees_split::{CallFilter, DistributionShare, FeeDestination};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_core::{H160, U256};
+use sp_runtime::{AccountId32, Percent};
+
+type DealWithTestFees = DealWithFees<Test>;
+type DealWithTestEVMFees = DealWithEVMFees<Test>;
+type ChargeTestFees = ChargeRoutedFees<Test>;
+
+const PAYER: AccountId = AccountId32::new([1u8; 32]);
+const POT: AccountId = AccountId32::new([7u8; 32]);
+
+/// Wasm of `(module (func (export "call")) (func (export "deploy")))`,
+/// a contract that does nothing at all
+const DUMMY_CONTRACT: &[u8] = &[
+	0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00, 0x03, 0x03,
+	0x02, 0x00, 0x00, 0x07, 0x11, 0x02, 0x04, 0x63, 0x61, 0x6c, 0x6c, 0x00, 0x00, 0x06, 0x64, 0x65,
+	0x70, 0x6c, 0x6f, 0x79, 0x00, 0x01, 0x0a, 0x07, 0x02, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b,
+];
+
+fn share(destination: FeeDestination<AccountId>, percent: u8) -> DistributionShare<AccountId> {
+	DistributionShare::new(destination, Percent::from_percent(percent))
+}
+
+#[test]
+fn fees_and_tips_follow_distribution_table() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeesSplit::set_fee_distribution(
+			RuntimeOrigin::root(),
+			vec![
+				share(FeeDestination::Treasury, 50),
+				share(FeeDestination::Burn, 20),
+				share(FeeDestination::Account(POT), 30),
+			]
+			.try_into()
+			.unwrap()
+		));
+		assert_ok!(FeesSplit::set_tip_distribution(
+			RuntimeOrigin::root(),
+			vec![share(FeeDestination::Account(POT), 100)]
+				.try_into()
+				.unwrap()
+		));
+
+		let fees = Balances::issue(100 * DOLLARS);
+		let tips = Balances::issue(10 * DOLLARS);
+		DealWithTestFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+		assert_eq!(Balances::free_balance(TREASURY), 50 * DOLLARS);
+		assert_eq!(Balances::free_balance(POT), 40 * DOLLARS);
+		assert_eq!(Balances::total_issuance(), 90 * DOLLARS);
+	});
+}
+
+#[test]
+fn evm_fees_follow_substrate_fee_split() {
+	let payer = H160::repeat_byte(1);
+	let base_fee = 100 * DOLLARS;
+	let tip = 10 * DOLLARS;
+	let account = || <Test as pallet_evm::Config>::AddressMapping::into_account_id(payer);
+
+	// Balance of treasury and pot along with total issuance after
+	// fee and tip of `payer` have been charged with `charge`
+	let charged_with = |charge: &dyn Fn()| {
+		new_test_ext().execute_with(|| {
+			assert_ok!(FeesSplit::set_fee_distribution(
+				RuntimeOrigin::root(),
+				vec![
+					share(FeeDestination::Treasury, 50),
+					share(FeeDestination::Burn, 20),
+					share(FeeDestination::Account(POT), 30),
+				]
+				.try_into()
+				.unwrap()
+			));
+			assert_ok!(FeesSplit::set_tip_distribution(
+				RuntimeOrigin::root(),
+				vec![
+					share(FeeDestination::Treasury, 40),
+					share(FeeDestination::Account(POT), 60),
+				]
+				.try_into()
+				.unwrap()
+			));
+			let _ = Balances::deposit_creating(&account(), 1_000 * DOLLARS);
+
+			charge();
+			(
+				Balances::free_balance(TREASURY),
+				Balances::free_balance(POT),
+				Balances::total_issuance(),
+			)
+		})
+	};
+
+	let substrate_flow = charged_with(&|| {
+		let paid = Balances::withdraw(
+			&account(),
+			base_fee + tip,
+			WithdrawReasons::FEE,
+			ExistenceRequirement::KeepAlive,
+		)
+		.unwrap();
+		let (fee, tip) = paid.split(base_fee);
+		DealWithTestFees::on_unbalanceds(vec![fee, tip].into_iter());
+	});
+
+	let evm_flow = charged_with(&|| {
+		let total_fee = U256::from(base_fee + tip);
+		let withdrawn = DealWithTestEVMFees::withdraw_fee(&payer, total_fee).unwrap();
+		let priority_fee = DealWithTestEVMFees::correct_and_deposit_fee(
+			&payer,
+			total_fee,
+			U256::from(base_fee),
+			withdrawn,
+		);
+		DealWithTestEVMFees::pay_priority_fee(priority_fee);
+	});
+
+	assert_eq!(evm_flow, substrate_flow);
+	assert_eq!(evm_flow, (54 * DOLLARS, 36 * DOLLARS, 980 * DOLLARS));
+}
+
+#[test]
+fn fee_follows_routing_rule_of_call() {
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer {
+		dest: POT,
+		value: DOLLARS,
+	});
+	let encoded = transfer.encode();
+	let filter = CallFilter::call(encoded[0], encoded[1]);
+
+	// Charge 10 dollars of fee and refund 2 of them
+	let charge = |call: &RuntimeCall| {
+		let info = DispatchInfo::default();
+		let withdrawn = ChargeTestFees::withdraw_fee(&PAYER, call, &info, 10 * DOLLARS, 0).unwrap();
+		assert_ok!(ChargeTestFees::correct_and_deposit_fee(
+			&PAYER,
+			&info,
+			&PostDispatchInfo::default(),
+			8 * DOLLARS,
+			0,
+			withdrawn,
+		));
+	};
+	let treasury_balance = || Balances::free_balance(TREASURY);
+
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&PAYER, 1_000 * DOLLARS);
+		assert_ok!(FeesSplit::set_treasury_cut_percent(
+			RuntimeOrigin::root(),
+			100
+		));
+
+		// Without rule default split applies
+		charge(&transfer);
+		assert_eq!(treasury_balance(), 8 * DOLLARS);
+		assert_eq!(Balances::free_balance(PAYER), 992 * DOLLARS);
+
+		// Rule of whole pallet
+		assert_ok!(FeesSplit::set_routing_rule(
+			RuntimeOrigin::root(),
+			CallFilter::pallet(encoded[0]),
+			vec![share(FeeDestination::Account(POT), 100)]
+				.try_into()
+				.unwrap()
+		));
+		charge(&transfer);
+		assert_eq!(treasury_balance(), 8 * DOLLARS);
+		assert_eq!(Balances::free_balance(POT), 8 * DOLLARS);
+
+		// Rule of exact call takes precedence. Transfer has no
+		// beneficiary so it's share follows default split
+		assert_ok!(FeesSplit::set_routing_rule(
+			RuntimeOrigin::root(),
+			filter,
+			vec![
+				share(FeeDestination::Beneficiary, 30),
+				share(FeeDestination::Account(POT), 70),
+			]
+			.try_into()
+			.unwrap()
+		));
+		charge(&transfer);
+		assert_eq!(treasury_balance(), 8 * DOLLARS + 240 * CENTS);
+		assert_eq!(Balances::free_balance(POT), 8 * DOLLARS + 560 * CENTS);
+	});
+}
+
+#[test]
+fn author_share_is_burned_without_author() {
+	new_test_ext().execute_with(|| {
+		// Whole fee and tip are author's with zero treasury cut
+		let fees = Balances::issue(100 * DOLLARS);
+		let tips = Balances::issue(10 * DOLLARS);
+		DealWithTestFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+		assert_eq!(Balances::total_issuance(), 0);
+
+		let totals = FeesSplit::era_totals(0).unwrap().totals;
+		assert_eq!(totals.fees, 100 * DOLLARS);
+		assert_eq!(totals.tips, 10 * DOLLARS);
+		assert_eq!(totals.burned, 110 * DOLLARS);
+		assert_eq!(totals.author, 0);
+	});
+}
+
+#[test]
+fn contract_deployer_follows_contract() {
+	let settle = |contract: &AccountId, deployer: Option<AccountId>| {
+		ChargeTestFees::settle_deployer(TargetContract {
+			contract: contract.clone(),
+			deployer,
+		})
+	};
+
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&PAYER, 1_000 * DOLLARS);
+		let contract = Contracts::bare_instantiate(
+			PAYER,
+			0,
+			Weight::from_ref_time(100_000_000_000),
+			None,
+			Code::Upload(DUMMY_CONTRACT.to_vec()),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		// Instantiation that did not take place is not recorded
+		settle(&POT, Some(PAYER));
+		assert_eq!(FeesSplit::contract_deployer(POT), None);
+
+		settle(&contract, Some(PAYER));
+		assert_eq!(FeesSplit::contract_deployer(&contract), Some(PAYER));
+
+		// Calling contract keeps it's deployer
+		settle(&contract, None);
+		assert_eq!(FeesSplit::contract_deployer(&contract), Some(PAYER));
+
+		// Deployer of contract that no longer exists is forgotten
+		FeesSplit::note_contract_deployer(&POT, &PAYER);
+		settle(&POT, None);
+		assert_eq!(FeesSplit::contract_deployer(POT), None);
+	});
+}
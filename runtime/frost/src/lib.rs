@@ -40,7 +40,10 @@ use sp_runtime::{
 		DispatchInfoOf, Dispatchable, IdentifyAccount, NumberFor, OpaqueKeys, PostDispatchInfoOf,
 		Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
+	transaction_validity::{
+		TransactionLongevity, TransactionPriority, TransactionSource, TransactionValidity,
+		TransactionValidityError,
+	},
 	ApplyExtrinsicResult, FixedPointNumber, MultiSignature, Perquintill,
 };
 use weights::{
@@ -62,7 +65,7 @@ use sp_std::boxed::Box;
 pub mod constants;
 pub mod impls;
 pub use constants::{currency, time::*};
-pub use impls::DealWithFees;
+pub use impls::{ChargeRoutedFees, DealWithEVMFees, DealWithFees};
 
 pub type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 // A few exports that help ease life for downstream crates.
@@ -88,7 +91,7 @@ use pallet_contracts::{weights::WeightInfo, Migration};
 use pallet_ethereum::{Call::transact, Transaction as EthereumTransaction};
 use pallet_evm::{Account as EVMAccount, EnsureAddressTruncated, HashedAddressMapping, Runner};
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
+use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Percent, Permill};
@@ -407,7 +410,7 @@ parameter_types! {
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction = ChargeRoutedFees;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = constants::fee::WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -495,7 +498,7 @@ impl pallet_evm::Config for Runtime {
 	type ChainId = ChainId;
 	type BlockGasLimit = BlockGasLimit;
 	type Runner = pallet_evm::runner::stack::Runner<Self>;
-	type OnChargeTransaction = ();
+	type OnChargeTransaction = DealWithEVMFees;
 	type FindAuthor = FindAuthorTruncated<Aura>;
 }
 
@@ -622,21 +625,56 @@ impl pallet_simple_inflation::Config for Runtime {
 	type Beneficiary = Beneficiary;
 }
 
-impl pallet_fees_split::Config for Runtime {}
+parameter_types! {
+	pub const FeesEraLength: BlockNumber = DAYS;
+}
+
+impl pallet_fees_split::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxBeneficiaries = ConstU32<8>;
+	type Balance = Balance;
+	type EraLength = FeesEraLength;
+	type RetainedEras = ConstU32<90>;
+}
 
-const AIRDROP_VESTING_TERMS: pallet_airdrop::VestingTerms = pallet_airdrop::VestingTerms {
+/// Vesting terms of the airdrop campaign created at genesis
+pub const AIRDROP_VESTING_TERMS: pallet_airdrop::VestingTerms = pallet_airdrop::VestingTerms {
 	defi_instant_percentage: 30,
 	non_defi_instant_percentage: 20,
 	vesting_period: 7776000,
 };
+parameter_types! {
+	pub const AirdropUnsignedClaimPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+	pub const AirdropUnsignedClaimLongevity: TransactionLongevity = 64;
+	pub AirdropUnclaimedFundsBeneficiary: AccountId = TreasuryPalletId::get().into_account_truncating();
+	pub AirdropCreditorTopUpSource: AccountId = TreasuryPalletId::get().into_account_truncating();
+	/// Signed icon messages can be used to claim for a week after they are signed
+	pub const AirdropSignedClaimValidity: u64 = 7 * 24 * 60 * 60 * 1000;
+	/// Id of ICON mainnet whose transactions are signed for claim
+	pub const AirdropIconNetworkId: u64 = 1;
+	/// Terms used by airdrop before campaigns were introduced
+	pub const AirdropLegacyVestingTerms: pallet_airdrop::VestingTerms = AIRDROP_VESTING_TERMS;
+}
+
 impl pallet_airdrop::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type AirdropWeightInfo = AirdropWeightInfo<Runtime>;
 	type BalanceTypeConversion = ConvertInto;
 	type MerkelProofValidator = pallet_airdrop::merkle::AirdropMerkleValidator<Runtime>;
+	type MerkleLeafEncoder = pallet_airdrop::merkle::IconLeafEncoder;
+	type MerkleNodeHasher = pallet_airdrop::merkle::Blake2MerkleHasher;
+	type SourceChainVerifier = pallet_airdrop::icon::IconVerifier<AirdropIconNetworkId>;
 	type MaxProofSize = ConstU32<21>;
-	const VESTING_TERMS: pallet_airdrop::VestingTerms = AIRDROP_VESTING_TERMS;
+	type MaxClaimBatchSize = ConstU32<50>;
+	type UnsignedClaimPriority = AirdropUnsignedClaimPriority;
+	type UnsignedClaimLongevity = AirdropUnsignedClaimLongevity;
+	type UnclaimedFundsBeneficiary = AirdropUnclaimedFundsBeneficiary;
+	type CreditorTopUpSource = AirdropCreditorTopUpSource;
+	type MaxFundedCampaigns = ConstU32<16>;
+	type UnixTime = Timestamp;
+	type SignedClaimValidity = AirdropSignedClaimValidity;
+	type OffchainClaimSubmitter = ();
 }
 
 impl pallet_utility::Config for Runtime {
@@ -1105,8 +1143,8 @@ construct_runtime!(
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Config<T>, Event<T>} = 12,
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>} = 13,
 		SimpleInflation: pallet_simple_inflation::{Pallet, Call, Storage, Config<T>} = 14,
-		FeesSplit: pallet_fees_split::{Pallet, Call, Storage, Config<T>} = 15,
-		Airdrop: pallet_airdrop::{Pallet, Call, Storage, Config<T>, Event<T>} = 16,
+		FeesSplit: pallet_fees_split::{Pallet, Call, Storage, Config<T>, Event<T>} = 15,
+		Airdrop: pallet_airdrop::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned} = 16,
 
 		// Contracts stuff
 		Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>} = 20,
@@ -1193,6 +1231,13 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic =
 	fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	Migration<Runtime>,
+	pallet_airdrop::migrations::v2::MigrateToV2<Runtime, AirdropLegacyVestingTerms>,
+	pallet_fees_split::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1200,7 +1245,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	Migration<Runtime>,
+	Migrations,
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1516,6 +1561,57 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_fees_split_runtime_api::FeesSplitApi<Block, Balance, BlockNumber> for Runtime {
+		fn current_era() -> pallet_fees_split_runtime_api::EraIndex {
+			FeesSplit::current_era()
+		}
+
+		fn era_totals(
+			era: pallet_fees_split_runtime_api::EraIndex,
+		) -> Option<pallet_fees_split_runtime_api::EraFeeTotals<Balance, BlockNumber>> {
+			FeesSplit::era_totals(era)
+		}
+
+		fn recent_totals() -> Vec<pallet_fees_split_runtime_api::EraFeeTotals<Balance, BlockNumber>> {
+			FeesSplit::recent_totals()
+		}
+	}
+
+	impl pallet_airdrop_runtime_api::AirdropApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn preview_claim(
+			campaign_id: u32,
+			icon_address: [u8; 20],
+			amount: Balance,
+			defi_user: bool,
+		) -> Result<
+			pallet_airdrop_runtime_api::ClaimPreview<AccountId, Balance, BlockNumber>,
+			sp_runtime::DispatchError,
+		> {
+			Airdrop::preview_claim(campaign_id, icon_address, amount, defi_user)
+		}
+
+		fn verify_merkle_proof(
+			campaign_id: u32,
+			icon_address: [u8; 20],
+			amount: Balance,
+			defi_user: bool,
+			proofs: Vec<[u8; 32]>,
+		) -> Result<pallet_airdrop_runtime_api::MerkleProofCheck, sp_runtime::DispatchError> {
+			Airdrop::dry_run_merkle_proof(campaign_id, &icon_address, amount, defi_user, proofs)
+		}
+
+		fn pending_exchange_claims() -> Vec<pallet_airdrop_runtime_api::PendingExchangeClaim<Balance>> {
+			Airdrop::pending_exchange_claims()
+		}
+
+		fn partial_snapshots(
+			start_key: Option<pallet_airdrop_runtime_api::SnapshotKey>,
+			limit: u32,
+		) -> pallet_airdrop_runtime_api::PartialSnapshotsPage<AccountId, Balance, BlockNumber> {
+			Airdrop::partial_snapshots(start_key, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -49,40 +49,196 @@ impl<T: frame_system::Config> pallet_airdrop::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
 	// Storage: Airdrop AirdropChainState (r:1 w:0)
-	// Storage: Airdrop MerkleRoot (r:1 w:0)
-	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
 	// Storage: Airdrop IceIconMap (r:1 w:1)
-	// Storage: Airdrop CreditorAccount (r:1 w:0)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
 	fn dispatch_user_claim() -> Weight {
 		// Minimum execution time: 300_150 nanoseconds.
 		Weight::from_ref_time(314_968_000)
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	// Storage: Airdrop AirdropChainState (r:1 w:0)
-	// Storage: Airdrop ExchangeAccountsMap (r:1 w:0)
-	// Storage: Airdrop MerkleRoot (r:1 w:0)
-	// Storage: Airdrop CreditorAccount (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop SettledExchangeAccounts (r:1 w:1)
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
-	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
 	fn dispatch_exchange_claim() -> Weight {
 		// Minimum execution time: 177_925 nanoseconds.
 		Weight::from_ref_time(181_012_000)
-			.saturating_add(T::DbWeight::get().reads(10))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
-	// Storage: Airdrop MerkleRoot (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:1 w:1)
 	fn change_merkle_root() -> Weight {
 		// Minimum execution time: 39_642 nanoseconds.
 		Weight::from_ref_time(40_834_000)
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
+	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn dispatch_user_claims_batch(n: u32, ) -> Weight {
+		Weight::from_ref_time(36_214_000)
+			.saturating_add(Weight::from_ref_time(283_551_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Airdrop NextCampaignId (r:1 w:1)
+	// Storage: Airdrop Campaigns (r:0 w:1)
+	// Storage: Airdrop CreditorCampaign (r:1 w:1)
+	fn create_campaign() -> Weight {
+		Weight::from_ref_time(42_375_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	fn update_campaign_state() -> Weight {
+		Weight::from_ref_time(40_226_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	fn set_vesting_terms() -> Weight {
+		Weight::from_ref_time(40_517_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	fn set_claim_deadline() -> Weight {
+		Weight::from_ref_time(40_992_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Locks (r:1 w:0)
+	// Storage: Airdrop CreditorCampaign (r:0 w:1)
+	// Storage: Airdrop FundedCampaigns (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:0 w:1)
+	// Storage: Airdrop CreditorBalanceIsLow (r:0 w:1)
+	// Storage: Airdrop PayloadsToClear (r:0 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:0)
+	fn sweep_unclaimed_funds() -> Weight {
+		Weight::from_ref_time(49_310_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: Airdrop SettledExchangeAccounts (r:1 w:0)
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	fn add_exchange_account() -> Weight {
+		Weight::from_ref_time(37_118_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	fn update_exchange_account() -> Weight {
+		Weight::from_ref_time(35_902_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Airdrop ExchangeAccountsMap (r:1 w:1)
+	fn remove_exchange_account() -> Weight {
+		Weight::from_ref_time(35_640_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: Airdrop ConsumedPayloads (r:1 w:1)
+	// Storage: Airdrop IceIconMap (r:1 w:1)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	fn dispatch_evm_user_claim() -> Weight {
+		Weight::from_ref_time(319_547_000)
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop IconSnapshotMap (r:1 w:1)
+	// Storage: Airdrop AirdropChainState (r:1 w:0)
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop MigrationCursor (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:1 w:0)
+	// Storage: Airdrop CreditorBalanceIsLow (r:1 w:1)
+	// Storage: Airdrop DistributedAmount (r:1 w:1)
+	fn complete_partial_claim() -> Weight {
+		Weight::from_ref_time(252_910_000)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: Airdrop Campaigns (r:1 w:0)
+	// Storage: Airdrop FundedCampaigns (r:1 w:1)
+	// Storage: Airdrop CreditorFunding (r:0 w:1)
+	// Storage: Airdrop CreditorBalanceIsLow (r:0 w:1)
+	fn set_creditor_funding() -> Weight {
+		Weight::from_ref_time(35_102_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn validate_ice_signature_sr25519() -> Weight {
+		Weight::from_ref_time(66_418_000)
+	}
+	fn validate_ice_signature_ed25519() -> Weight {
+		Weight::from_ref_time(64_107_000)
+	}
+	fn validate_ice_signature_ecdsa() -> Weight {
+		Weight::from_ref_time(86_993_000)
+	}
+	// Storage: Airdrop ServerAccount (r:1 w:0)
+	// Storage: Airdrop RevokedIconAddresses (r:1 w:1)
+	fn revoke_icon_address() -> Weight {
+		Weight::from_ref_time(33_576_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
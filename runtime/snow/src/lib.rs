@@ -53,7 +53,10 @@ use sp_runtime::{
 		AccountIdLookup, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, DispatchInfoOf,
 		Dispatchable, IdentifyAccount, PostDispatchInfoOf, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
+	transaction_validity::{
+		TransactionLongevity, TransactionPriority, TransactionSource, TransactionValidity,
+		TransactionValidityError,
+	},
 	ApplyExtrinsicResult, FixedPointNumber, MultiSignature, Perquintill,
 };
 
@@ -68,7 +71,7 @@ use sp_std::boxed::Box;
 pub mod constants;
 pub mod impls;
 pub use constants::{currency, time::*};
-pub use impls::DealWithFees;
+pub use impls::{ChargeRoutedFees, DealWithEVMFees, DealWithFees};
 
 pub type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
@@ -101,7 +104,7 @@ use pallet_contracts::{weights::WeightInfo, Migration};
 use pallet_ethereum::{Call::transact, Transaction as EthereumTransaction};
 use pallet_evm::{Account as EVMAccount, EnsureAddressTruncated, HashedAddressMapping, Runner};
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
+use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use sp_runtime::traits::AccountIdConversion;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -494,7 +497,7 @@ parameter_types! {
 pub type WeightToFee = constants::fee::WeightToFee;
 
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction = ChargeRoutedFees;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = constants::fee::WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -553,7 +556,7 @@ impl pallet_evm::Config for Runtime {
 	type ChainId = ChainId;
 	type BlockGasLimit = BlockGasLimit;
 	type Runner = pallet_evm::runner::stack::Runner<Self>;
-	type OnChargeTransaction = ();
+	type OnChargeTransaction = DealWithEVMFees;
 	type FindAuthor = FindAuthorTruncated<Aura>;
 }
 
@@ -728,7 +731,17 @@ impl pallet_simple_inflation::Config for Runtime {
 	type Beneficiary = Beneficiary;
 }
 
-impl pallet_fees_split::Config for Runtime {}
+parameter_types! {
+	pub const FeesEraLength: BlockNumber = DAYS;
+}
+
+impl pallet_fees_split::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxBeneficiaries = ConstU32<8>;
+	type Balance = Balance;
+	type EraLength = FeesEraLength;
+	type RetainedEras = ConstU32<90>;
+}
 
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -1159,19 +1172,44 @@ impl pallet_base_fee::Config for Runtime {
 
 impl pallet_randomness_collective_flip::Config for Runtime {}
 
-const AIRDROP_VESTING_TERMS: pallet_airdrop::VestingTerms = pallet_airdrop::VestingTerms {
+/// Vesting terms of the airdrop campaign created at genesis
+pub const AIRDROP_VESTING_TERMS: pallet_airdrop::VestingTerms = pallet_airdrop::VestingTerms {
 	defi_instant_percentage: 100,
 	non_defi_instant_percentage: 100,
 	vesting_period: 7776000,
 };
 
+parameter_types! {
+	pub const AirdropUnsignedClaimPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+	pub const AirdropUnsignedClaimLongevity: TransactionLongevity = 64;
+	pub AirdropUnclaimedFundsBeneficiary: AccountId = TreasuryPalletId::get().into_account_truncating();
+	pub AirdropCreditorTopUpSource: AccountId = TreasuryPalletId::get().into_account_truncating();
+	/// Signed icon messages can be used to claim for a week after they are signed
+	pub const AirdropSignedClaimValidity: u64 = 7 * 24 * 60 * 60 * 1000;
+	/// Id of ICON mainnet whose transactions are signed for claim
+	pub const AirdropIconNetworkId: u64 = 1;
+	/// Terms used by airdrop before campaigns were introduced
+	pub const AirdropLegacyVestingTerms: pallet_airdrop::VestingTerms = AIRDROP_VESTING_TERMS;
+}
+
 impl pallet_airdrop::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type BalanceTypeConversion = ConvertInto;
 	type MerkelProofValidator = pallet_airdrop::merkle::AirdropMerkleValidator<Runtime>;
+	type MerkleLeafEncoder = pallet_airdrop::merkle::IconLeafEncoder;
+	type MerkleNodeHasher = pallet_airdrop::merkle::Blake2MerkleHasher;
+	type SourceChainVerifier = pallet_airdrop::icon::IconVerifier<AirdropIconNetworkId>;
 	type MaxProofSize = ConstU32<21>;
-	const VESTING_TERMS: pallet_airdrop::VestingTerms = AIRDROP_VESTING_TERMS;
+	type MaxClaimBatchSize = ConstU32<50>;
+	type UnsignedClaimPriority = AirdropUnsignedClaimPriority;
+	type UnsignedClaimLongevity = AirdropUnsignedClaimLongevity;
+	type UnclaimedFundsBeneficiary = AirdropUnclaimedFundsBeneficiary;
+	type CreditorTopUpSource = AirdropCreditorTopUpSource;
+	type MaxFundedCampaigns = ConstU32<16>;
+	type UnixTime = Timestamp;
+	type SignedClaimValidity = AirdropSignedClaimValidity;
+	type OffchainClaimSubmitter = ();
 	type AirdropWeightInfo = AirdropWeightInfo<Runtime>;
 }
 
@@ -1199,8 +1237,8 @@ construct_runtime!(
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Config<T>, Event<T>} = 22,
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>} = 23,
 		SimpleInflation: pallet_simple_inflation::{Pallet, Call, Storage, Config<T>} = 24,
-		FeesSplit: pallet_fees_split::{Pallet, Call, Storage, Config<T>} = 25,
-		Airdrop: pallet_airdrop::{Pallet, Call, Storage, Config<T>, Event<T>} = 26,
+		FeesSplit: pallet_fees_split::{Pallet, Call, Storage, Config<T>, Event<T>} = 25,
+		Airdrop: pallet_airdrop::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned} = 26,
 
 		// Contracts stuff
 		Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>} = 30,
@@ -1295,6 +1333,13 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic =
 	fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	Migration<Runtime>,
+	pallet_airdrop::migrations::v2::MigrateToV2<Runtime, AirdropLegacyVestingTerms>,
+	pallet_fees_split::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1302,7 +1347,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	Migration<Runtime>,
+	Migrations,
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1623,6 +1668,57 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_fees_split_runtime_api::FeesSplitApi<Block, Balance, BlockNumber> for Runtime {
+		fn current_era() -> pallet_fees_split_runtime_api::EraIndex {
+			FeesSplit::current_era()
+		}
+
+		fn era_totals(
+			era: pallet_fees_split_runtime_api::EraIndex,
+		) -> Option<pallet_fees_split_runtime_api::EraFeeTotals<Balance, BlockNumber>> {
+			FeesSplit::era_totals(era)
+		}
+
+		fn recent_totals() -> Vec<pallet_fees_split_runtime_api::EraFeeTotals<Balance, BlockNumber>> {
+			FeesSplit::recent_totals()
+		}
+	}
+
+	impl pallet_airdrop_runtime_api::AirdropApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn preview_claim(
+			campaign_id: u32,
+			icon_address: [u8; 20],
+			amount: Balance,
+			defi_user: bool,
+		) -> Result<
+			pallet_airdrop_runtime_api::ClaimPreview<AccountId, Balance, BlockNumber>,
+			sp_runtime::DispatchError,
+		> {
+			Airdrop::preview_claim(campaign_id, icon_address, amount, defi_user)
+		}
+
+		fn verify_merkle_proof(
+			campaign_id: u32,
+			icon_address: [u8; 20],
+			amount: Balance,
+			defi_user: bool,
+			proofs: Vec<[u8; 32]>,
+		) -> Result<pallet_airdrop_runtime_api::MerkleProofCheck, sp_runtime::DispatchError> {
+			Airdrop::dry_run_merkle_proof(campaign_id, &icon_address, amount, defi_user, proofs)
+		}
+
+		fn pending_exchange_claims() -> Vec<pallet_airdrop_runtime_api::PendingExchangeClaim<Balance>> {
+			Airdrop::pending_exchange_claims()
+		}
+
+		fn partial_snapshots(
+			start_key: Option<pallet_airdrop_runtime_api::SnapshotKey>,
+			limit: u32,
+		) -> pallet_airdrop_runtime_api::PartialSnapshotsPage<AccountId, Balance, BlockNumber> {
+			Airdrop::partial_snapshots(start_key, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,